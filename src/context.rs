@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+/// A stack of context lines built by [`ResultExt::chain_context`](crate::ResultExt::chain_context),
+/// outermost first.
+///
+/// It deliberately does not implement [`Display`], so that chaining more context
+/// onto it extends the stack instead of collapsing it into a single line.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContextChain(Vec<String>);
+
+impl ContextChain {
+    /// Returns the context lines, outermost first.
+    pub fn lines(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Consumes the chain, returning its context lines.
+    pub fn into_vec(self) -> Vec<String> {
+        self.0
+    }
+
+    pub(crate) fn push_front(&mut self, line: String) {
+        self.0.insert(0, line);
+    }
+}
+
+impl From<ContextChain> for Vec<String> {
+    fn from(chain: ContextChain) -> Self {
+        chain.0
+    }
+}
+
+/// Errors that context can be chained onto with [`ResultExt::chain_context`](crate::ResultExt::chain_context).
+///
+/// Any [`Display`] type starts a new chain, while a [`ContextChain`] is extended.
+pub trait IntoContextChain {
+    /// Converts `self` into a [`ContextChain`].
+    fn into_context_chain(self) -> ContextChain;
+}
+
+impl<E> IntoContextChain for E
+where
+    E: Display,
+{
+    fn into_context_chain(self) -> ContextChain {
+        ContextChain(vec![self.to_string()])
+    }
+}
+
+impl IntoContextChain for ContextChain {
+    fn into_context_chain(self) -> ContextChain {
+        self
+    }
+}
@@ -0,0 +1,297 @@
+use core::iter::{Product, Scan, Sum};
+
+/// Extra methods for [`Iterator`]s.
+pub trait IteratorExt: Iterator {
+    /// Sums the wrapped values if every item is [`Some`],
+    /// or returns [`None`] as soon as a [`None`] item is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// assert_eq!([Some(1), Some(2), Some(3)].into_iter().sum_options(), Some(6));
+    /// assert_eq!([Some(1), None, Some(3)].into_iter().sum_options(), None);
+    /// ```
+    #[must_use]
+    fn sum_options<S>(self) -> Option<S>
+    where
+        Self: Iterator<Item = Option<S>>,
+        S: Sum;
+
+    /// Multiplies the wrapped values if every item is [`Some`],
+    /// or returns [`None`] as soon as a [`None`] item is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// assert_eq!([Some(2), Some(3), Some(4)].into_iter().product_options(), Some(24));
+    /// assert_eq!([Some(2), None, Some(4)].into_iter().product_options(), None);
+    /// ```
+    #[must_use]
+    fn product_options<S>(self) -> Option<S>
+    where
+        Self: Iterator<Item = Option<S>>,
+        S: Product;
+
+    /// Applies `f` to every item and returns the last [`Some`] it produced.
+    ///
+    /// Unlike [`Iterator::find_map`], this always consumes the whole iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let readings = ["12", "oops", "15", "", "9", "n/a"];
+    ///
+    /// assert_eq!(readings.iter().last_some(|r| r.parse::<i32>().ok()), Some(9));
+    /// assert_eq!(readings.iter().last_some(|r| r.parse::<f32>().ok().filter(|&v| v > 100.0)), None);
+    /// ```
+    #[must_use]
+    fn last_some<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnMut(Self::Item) -> Option<U>;
+
+    /// Returns the only item of the iterator, or [`None`]
+    /// if it yields no items or more than one.
+    ///
+    /// At most two items are consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// assert_eq!(Vec::<i32>::new().into_iter().single(), None);
+    /// assert_eq!(vec![1].into_iter().single(), Some(1));
+    /// assert_eq!(vec![1, 2].into_iter().single(), None);
+    ///
+    /// let mut numbers = 1..;
+    ///
+    /// assert_eq!(numbers.by_ref().single(), None);
+    /// assert_eq!(numbers.next(), Some(3));
+    /// ```
+    #[must_use]
+    fn single(self) -> Option<Self::Item>;
+
+    /// Returns the only item of the iterator, [`None`] if there are no items,
+    /// or `Err(())` if there is more than one.
+    ///
+    /// Like [`single`](IteratorExt::single), but tells "too many" apart from "none".
+    /// At most two items are consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// assert_eq!(Vec::<i32>::new().into_iter().at_most_one(), Ok(None));
+    /// assert_eq!(vec![1].into_iter().at_most_one(), Ok(Some(1)));
+    /// assert_eq!(vec![1, 2, 3].into_iter().at_most_one(), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    #[must_use = "if unused, the items consumed from the iterator are lost"]
+    fn at_most_one(self) -> Result<Option<Self::Item>, ()>;
+
+    /// Collects the wrapped `Ok` values into `C`,
+    /// or returns the first error, without consuming any further items.
+    ///
+    /// Same as collecting into a [`Result`], but with a more discoverable name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let numbers: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect_results();
+    /// assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    ///
+    /// let word: Result<String, &str> = [Ok('a'), Ok('b'), Ok('c')].into_iter().collect_results();
+    /// assert_eq!(word.as_deref(), Ok("abc"));
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let mut items = [Ok(1), Err("bad"), Ok(3)].into_iter();
+    /// let collected: Result<Vec<i32>, _> = items.by_ref().collect_results();
+    ///
+    /// assert_eq!(collected, Err("bad"));
+    /// assert_eq!(items.next(), Some(Ok(3)));
+    /// ```
+    fn collect_results<T, E, C>(self) -> Result<C, E>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>;
+
+    /// Collects all wrapped `Ok` values if there are no errors,
+    /// or all errors otherwise, discarding the `Ok` values.
+    ///
+    /// Unlike [`collect_results`](IteratorExt::collect_results), this doesn't stop at the first
+    /// error. Both vectors keep the order of the source items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let all_ok = [Ok(1), Ok(2), Ok::<_, &str>(3)].into_iter().collect_all_results();
+    /// assert_eq!(all_ok, Ok(vec![1, 2, 3]));
+    ///
+    /// let mixed = [Ok(1), Err("a"), Ok(3), Err("b")].into_iter().collect_all_results();
+    /// assert_eq!(mixed, Err(vec!["a", "b"]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn collect_all_results<T, E>(self) -> Result<Vec<T>, Vec<E>>
+    where
+        Self: Iterator<Item = Result<T, E>>;
+
+    /// Threads a mutable state through `f` for every item,
+    /// yielding what `f` returns until it produces [`None`].
+    ///
+    /// Same as [`Iterator::scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let totals: Vec<_> = [3, 1, 4, -1, 5]
+    ///     .into_iter()
+    ///     .running_some(0, |sum, x| {
+    ///         (x >= 0).then(|| {
+    ///             *sum += x;
+    ///             *sum
+    ///         })
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(totals, [3, 4, 8]);
+    /// ```
+    fn running_some<S, U, F>(self, init: S, f: F) -> Scan<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> Option<U>;
+
+    /// Applies `f` to items while they satisfy `keep_going`,
+    /// and returns the first [`Some`] it produced.
+    ///
+    /// Stops at the first item rejected by `keep_going`,
+    /// which makes it suitable for searching ordered or endless iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::IteratorExt;
+    ///
+    /// let square_above = |min: u64, max: u64| {
+    ///     (1..).find_map_while(|n| n * n <= max, |n| (n * n > min).then(|| n * n))
+    /// };
+    ///
+    /// assert_eq!(square_above(50, 100), Some(64));
+    /// assert_eq!(square_above(50, 60), None); // stops at 8 * 8 instead of running forever
+    /// ```
+    #[must_use]
+    fn find_map_while<U, P, F>(self, keep_going: P, f: F) -> Option<U>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        F: FnMut(Self::Item) -> Option<U>;
+}
+
+impl<I> IteratorExt for I
+where
+    I: Iterator,
+{
+    fn sum_options<S>(self) -> Option<S>
+    where
+        Self: Iterator<Item = Option<S>>,
+        S: Sum,
+    {
+        self.sum()
+    }
+
+    fn product_options<S>(self) -> Option<S>
+    where
+        Self: Iterator<Item = Option<S>>,
+        S: Product,
+    {
+        self.product()
+    }
+
+    fn last_some<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnMut(Self::Item) -> Option<U>,
+    {
+        self.filter_map(f).last()
+    }
+
+    fn single(mut self) -> Option<Self::Item> {
+        let first = self.next()?;
+
+        match self.next() {
+            Some(_) => None,
+            None => Some(first),
+        }
+    }
+
+    fn at_most_one(mut self) -> Result<Option<Self::Item>, ()> {
+        let Some(first) = self.next() else {
+            return Ok(None);
+        };
+
+        match self.next() {
+            Some(_) => Err(()),
+            None => Ok(Some(first)),
+        }
+    }
+
+    fn collect_results<T, E, C>(self) -> Result<C, E>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        self.collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn collect_all_results<T, E>(self) -> Result<Vec<T>, Vec<E>>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+
+        for item in self {
+            match item {
+                Ok(x) if errs.is_empty() => oks.push(x),
+                Ok(_) => {}
+                Err(e) => errs.push(e),
+            }
+        }
+
+        if errs.is_empty() {
+            Ok(oks)
+        } else {
+            Err(errs)
+        }
+    }
+
+    fn running_some<S, U, F>(self, init: S, f: F) -> Scan<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> Option<U>,
+    {
+        self.scan(init, f)
+    }
+
+    fn find_map_while<U, P, F>(self, keep_going: P, f: F) -> Option<U>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        F: FnMut(Self::Item) -> Option<U>,
+    {
+        self.take_while(keep_going).find_map(f)
+    }
+}
@@ -1,14 +1,33 @@
-//! Additional utilities for common [`std`] types.
+//! Additional utilities for common [`std`](https://doc.rust-lang.org/std/) types.
 //!
 //! New methods are added via the traits:
 //!
 //! ```
+//! use option_extra::IteratorExt;
+//! use option_extra::OptionBoolExt;
 //! use option_extra::OptionExt;
+//! use option_extra::OptionRefExt;
+//! use option_extra::OptionResultExt;
 //! use option_extra::ResultExt;
+//! use option_extra::ResultOptionExt;
+//! use option_extra::ResultResultExt;
 //! ```
+//!
+//! The crate is `no_std` unless the default `std` feature is enabled,
+//! which adds methods relying on the standard library,
+//! as well as the `OptionVecExt` trait.
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "std")]
+mod context;
+mod iterator;
 mod macros;
 mod option;
 mod result;
 
-pub use option::OptionExt;
-pub use result::ResultExt;
+#[cfg(feature = "std")]
+pub use context::{ContextChain, IntoContextChain};
+pub use iterator::IteratorExt;
+#[cfg(feature = "std")]
+pub use option::OptionVecExt;
+pub use option::{NoneError, OptionBoolExt, OptionExt, OptionRefExt, OptionResultExt};
+pub use result::{ResultExt, ResultOptionExt, ResultResultExt};
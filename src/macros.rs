@@ -5,10 +5,13 @@
 /// General syntax:
 ///
 /// ```man
-/// some!( if let <enum variant> [{ <ident>... }] = <expr> [, when <guard expr>] [=> <then expr>] )
+/// some!( if let <enum variant> [{ <ident>... }] = <expr> [, let <pat> = <expr>]... [, when <guard expr>] [, any_of [<expr>...]] [=> <then> [, or <default expr>]] )
+/// some!( if let [<element>...] = <expr> [, let <pat> = <expr>]... [, when <guard expr>] [, any_of [<expr>...]] [=> <then> [, or <default expr>]] )
+/// some!( fn <enum variant> [{ <ident>... } ...] )
 /// ```
 ///
-/// where `<ident>` is `<name> [:]`.
+/// where `<ident>` is `[copy | deref] <name> [: | as <local>]`, `<element>` is `<name> [@ ..]`
+/// and `<then>` is `<expr>` or `.<method>(<args>)...`.
 ///
 /// Currently, until compile-time reflection becomes a thing,
 /// you need to specify bindings when there are multiple fields in your variant:
@@ -62,6 +65,30 @@
 /// assert_eq!(a_only, [(10, true), (4, false)]);
 /// ```
 ///
+/// The `fn` form expands to a closure taking the matched value,
+/// so it can be passed to iterator adapters directly.
+/// Anything following the bindings works as in the `if let` form:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     A(i32, bool),
+///     B(u8),
+///     C
+/// }
+///
+/// use MyEnum::*;
+///
+/// let v = vec![A(10, true), B(0), C, B(1), A(4, false), C];
+///
+/// let bs: Vec<_> = v.iter().filter_map(some!(fn B)).collect();
+/// assert_eq!(bs, [&0, &1]);
+///
+/// let a_only: Vec<_> = v.into_iter().filter_map(some!(fn A {n, b}, when b => n)).collect();
+/// assert_eq!(a_only, [10]);
+/// ```
+///
 /// Works with tuple variants:
 ///
 /// ```
@@ -98,6 +125,88 @@
 /// assert_eq!(some!(if let MyEnum::Struct {id:, name:} = s), Some((20, "abcd")));
 /// ```
 ///
+/// Struct fields can be captured under a different name with `as`,
+/// which is then used in guards and mapped expressions:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Stock { n: u32, sku: &'static str },
+///     Other,
+/// }
+///
+/// let s = MyEnum::Stock { n: 3, sku: "A-1" };
+///
+/// assert_eq!(
+///     some!(if let MyEnum::Stock { n as count, sku as code } = s, when count > 0 => format!("{code} x{count}")),
+///     Some("A-1 x3".to_owned())
+/// );
+/// ```
+///
+/// When matching on a reference, prefix [`Copy`] fields with `copy`
+/// to get them by value instead of by reference, also in guards and mapped expressions:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Entry { id: u16, name: String },
+///     Other,
+/// }
+///
+/// let e = MyEnum::Entry { id: 20, name: "abcd".to_owned() };
+///
+/// let copied: Option<(u16, &String)> = some!(if let MyEnum::Entry { copy id:, name: } = &e);
+/// let referenced: Option<(&u16, &String)> = some!(if let MyEnum::Entry { id:, name: } = &e);
+///
+/// assert_eq!(copied, Some((20, &"abcd".to_owned())));
+/// assert_eq!(referenced, Some((&20, &"abcd".to_owned())));
+/// assert_eq!(some!(if let MyEnum::Entry { copy id:, name: } = &e, when id > 10 => id + 1), Some(21));
+/// ```
+///
+/// Prefix a [`Box`](https://doc.rust-lang.org/std/boxed/struct.Box.html) field with `deref` to move the boxed value out of it.
+/// This requires matching on an owned value,
+/// since the boxed value cannot be moved out through a reference:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum Expr {
+///     Neg(Box<i32>),
+///     Add(Box<i32>, Box<i32>),
+/// }
+///
+/// let neg = Expr::Neg(Box::new(1));
+/// let add = Expr::Add(Box::new(2), Box::new(3));
+///
+/// assert_eq!(some!(if let Expr::Neg { deref x } = neg), Some(1));
+/// assert_eq!(some!(if let Expr::Add { deref a, deref b } = add => a + b), Some(5));
+/// ```
+///
+/// Slices and arrays can be destructured too, with `@ ..` binding the rest:
+///
+/// ```
+/// use option_extra::some;
+///
+/// let long = vec![1, 2, 3, 4];
+/// let short = vec![1];
+///
+/// assert_eq!(some!(if let [a, b, rest @ ..] = long.as_slice()), Some((&1, &2, &[3, 4][..])));
+/// assert_eq!(some!(if let [a, b, rest @ ..] = short.as_slice()), None);
+/// assert_eq!(some!(if let [x, y] = [10, 20] => x + y), Some(30));
+/// ```
+///
+/// As in regular slice patterns, the rest can only be bound once:
+///
+/// ```compile_fail
+/// use option_extra::some;
+///
+/// let v = vec![1, 2, 3];
+///
+/// some!(if let [head @ .., tail @ ..] = v.as_slice());
+/// ```
+///
 /// Optionally add an expression to which the wrapped value will be mapped:
 ///
 /// ```
@@ -115,6 +224,54 @@
 /// assert_eq!(some!(if let MyEnum::Int { n } = v_bool => (n, n + 1)), None);
 /// ```
 ///
+/// With a single captured value, the mapping can also be a method chain called on it:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Text(String),
+///     Other,
+/// }
+///
+/// let v = MyEnum::Text("  abc ".to_owned());
+///
+/// assert_eq!(some!(if let MyEnum::Text { s } = v => .trim().to_owned()), Some("abc".to_owned()));
+/// ```
+///
+/// That is rejected when more than one value is captured:
+///
+/// ```compile_fail
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Pair(String, String),
+///     Other,
+/// }
+///
+/// let v = MyEnum::Pair("a".to_owned(), "b".to_owned());
+///
+/// some!(if let MyEnum::Pair { a, b } = v => .len());
+/// ```
+///
+/// Adding a fallback with `or` after the mapped expression makes the macro
+/// return the plain value instead of an [`Option`], using the fallback on mismatch:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Int(i32),
+///     Bool(bool),
+/// }
+///
+/// let v_int = MyEnum::Int(10);
+/// let v_bool = MyEnum::Bool(true);
+///
+/// assert_eq!(some!(if let MyEnum::Int { n } = v_int => n * 2, or -1), 20);
+/// assert_eq!(some!(if let MyEnum::Int { n } = v_bool => n * 2, or -1), -1);
+/// ```
+///
 /// You can also add guards to further constrain which wrapped values are allowed:
 ///
 /// ```
@@ -131,8 +288,98 @@
 /// assert_eq!(some!(if let MyEnum::Val {x} = v, when x % 2 == 0), Some(10));
 /// assert_eq!(some!(if let MyEnum::Val {x} = v_odd, when x % 2 == 0), None);
 /// ```
+///
+/// Plain structs can be destructured as well. Their patterns are irrefutable,
+/// so without a guard the result is always [`Some`]; combined with `when`,
+/// the guard alone decides:
+///
+/// ```
+/// use option_extra::some;
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// struct Meters(u32);
+///
+/// let p = Point { x: 3, y: -1 };
+///
+/// assert_eq!(some!(if let Point { copy x:, copy y: } = &p, when x > 0 && y > 0), None);
+/// assert_eq!(some!(if let Point { copy x:, copy y: } = &p, when x > 0 => x + y), Some(2));
+/// assert_eq!(some!(if let Meters { m } = Meters(12), when m > 10), Some(12));
+/// ```
+///
+/// `let` clauses compute locals once the pattern matches,
+/// which are then visible to the guard and the mapped expression.
+/// Multiple clauses run in order, so later ones can use earlier ones:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Word(&'static str),
+///     Other,
+/// }
+///
+/// fn vowels(s: &str) -> usize {
+///     s.chars().filter(|c| "aeiou".contains(*c)).count()
+/// }
+///
+/// let v = MyEnum::Word("banana");
+/// let v_dry = MyEnum::Word("rhythm");
+///
+/// assert_eq!(some!(if let MyEnum::Word { w } = v, let n = vowels(w), when n > 0 => n * 2), Some(6));
+/// assert_eq!(some!(if let MyEnum::Word { w } = v_dry, let n = vowels(w), when n > 0 => n * 2), None);
+/// assert_eq!(
+///     some!(if let MyEnum::Word { w } = MyEnum::Word("see"), let n = vowels(w), let ratio = n * 100 / w.len(), when ratio > 50 => ratio),
+///     Some(66)
+/// );
+/// ```
+///
+/// With a single captured value, `any_of` only accepts values equal to one of the listed ones,
+/// which requires the value's type to implement [`PartialEq`].
+/// It can be combined with `when`, in which case both must hold:
+///
+/// ```
+/// use option_extra::some;
+///
+/// enum Response {
+///     Code(u16),
+///     Timeout,
+/// }
+///
+/// let created = Response::Code(201);
+/// let missing = Response::Code(404);
+///
+/// assert_eq!(some!(if let Response::Code { c } = created, any_of [200, 201, 204]), Some(201));
+/// assert_eq!(some!(if let Response::Code { c } = missing, any_of [200, 201, 204]), None);
+/// assert_eq!(
+///     some!(if let Response::Code { c } = Response::Code(204), when c != 204, any_of [200, 201, 204]),
+///     None
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use option_extra::some;
+///
+/// enum MyEnum {
+///     Pair(i32, i32),
+///     Other,
+/// }
+///
+/// some!(if let MyEnum::Pair { a, b } = MyEnum::Pair(1, 2), any_of [(1, 2)]); // more than one value
+/// ```
 #[macro_export]
 macro_rules! some {
+    ( fn $p:path ) => {
+        |x| $crate::some!(if let $p = x)
+    };
+
+    ( fn $p:path {$($b:tt)+} $($rest:tt)* ) => {
+        |x| $crate::some!(if let $p {$($b)+} = x $($rest)*)
+    };
+
     ( if let $p:path = $x:expr ) => {
         match $x {
             $p(inner) => ::core::option::Option::Some(inner),
@@ -140,31 +387,351 @@ macro_rules! some {
         }
     };
 
-    ( if let $p:path {$($n:ident),+} = $x:expr $(, when $guard:expr)? ) => {
+    ( if let [$($n:ident $(@ $rest:tt)?),+] = $x:expr $(, let $l:pat = $lv:expr)* $(, when $guard:expr)? $(, any_of [$($any:expr),+ $(,)?])? $(=> $($tail:tt)+)? ) => {
+        $crate::some!(@emit [[$($n $(@ $rest)?),+]] [$([$n])+] [$(let $l = $lv;)*] [$x] [$(@any [$($any),+])? $($guard)?] $(=> $($tail)+)?)
+    };
+
+    ( if let $p:path {$($b:tt)+} = $x:expr $(, let $l:pat = $lv:expr)* $(, when $guard:expr)? $(, any_of [$($any:expr),+ $(,)?])? $(=> $($tail:tt)+)? ) => {
+        $crate::some!(@bind unknown [[$p] [$(let $l = $lv;)*] [$x] [$(@any [$($any),+])? $($guard)?] $(=> $($tail)+)?] [] [] [] $($b)+)
+    };
+
+    (@bind $kind:ident [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] deref $n:ident $($rest:tt)*) => {
+        $crate::some!(@bind $kind [$($ctx)*] [$($pats)*] [$($vals)*] [$($pre)*] copy $n $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] copy $n:ident as $($rest:tt)*) => {
+        $crate::some!(@bind struct [$($ctx)*] [] [] [] copy $n as $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] copy $n:ident : $($rest:tt)*) => {
+        $crate::some!(@bind struct [$($ctx)*] [] [] [] copy $n : $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] copy $n:ident $($rest:tt)*) => {
+        $crate::some!(@bind tuple [$($ctx)*] [] [] [] copy $n $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] $n:ident as $($rest:tt)*) => {
+        $crate::some!(@bind struct [$($ctx)*] [] [] [] $n as $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] $n:ident : $($rest:tt)*) => {
+        $crate::some!(@bind struct [$($ctx)*] [] [] [] $n : $($rest)*)
+    };
+
+    (@bind unknown [$($ctx:tt)*] [] [] [] $($rest:tt)*) => {
+        $crate::some!(@bind tuple [$($ctx)*] [] [] [] $($rest)*)
+    };
+
+    (@bind struct [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] copy $n:ident : $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind struct [$($ctx)*] [$($pats)* $n,] [$($vals)* [$n]] [$($pre)* let $n = *$n;] $($($rest)*)?)
+    };
+
+    (@bind struct [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] $n:ident : $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind struct [$($ctx)*] [$($pats)* $n,] [$($vals)* [$n]] [$($pre)*] $($($rest)*)?)
+    };
+
+    (@bind struct [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] copy $n:ident as $m:ident $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind struct [$($ctx)*] [$($pats)* $n: $m,] [$($vals)* [$m]] [$($pre)* let $m = *$m;] $($($rest)*)?)
+    };
+
+    (@bind struct [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] $n:ident as $m:ident $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind struct [$($ctx)*] [$($pats)* $n: $m,] [$($vals)* [$m]] [$($pre)*] $($($rest)*)?)
+    };
+
+    (@bind struct [[$($p:tt)*] [$($lets:tt)*] $($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*]) => {
+        $crate::some!(@emit [$($p)*{$($pats)*}] [$($vals)*] [$($pre)* $($lets)*] $($ctx)*)
+    };
+
+    (@bind tuple [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] copy $n:ident $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind tuple [$($ctx)*] [$($pats)* $n,] [$($vals)* [$n]] [$($pre)* let $n = *$n;] $($($rest)*)?)
+    };
+
+    (@bind tuple [$($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*] $n:ident $(, $($rest:tt)*)?) => {
+        $crate::some!(@bind tuple [$($ctx)*] [$($pats)* $n,] [$($vals)* [$n]] [$($pre)*] $($($rest)*)?)
+    };
+
+    (@bind tuple [[$($p:tt)*] [$($lets:tt)*] $($ctx:tt)*] [$($pats:tt)*] [$($vals:tt)*] [$($pre:tt)*]) => {
+        $crate::some!(@emit [$($p)*($($pats)*)] [$($vals)*] [$($pre)* $($lets)*] $($ctx)*)
+    };
+
+    (@when [] [$($yes:tt)*] [$($no:tt)*]) => {
+        $($yes)*
+    };
+
+    (@when [$guard:expr] [$($yes:tt)*] [$($no:tt)*]) => {
+        if $guard {
+            $($yes)*
+        } else {
+            $($no)*
+        }
+    };
+
+    (@emit $pat:tt [[$($val:tt)*]] $pre:tt $x:tt [@any [$($any:expr),+] $($guard:expr)?] $($tail:tt)*) => {
+        $crate::some!(@emit $pat [[$($val)*]] $pre $x [[$($any),+].contains(&$($val)*) $(&& ($guard))?] $($tail)*)
+    };
+
+    (@emit $pat:tt $vals:tt $pre:tt $x:tt [@any $($guard:tt)*] $($tail:tt)*) => {
+        ::core::compile_error!("`any_of` can only be used with a single captured value")
+    };
+
+    (@emit [$($pat:tt)*] [$([$($val:tt)*])+] [$($pre:tt)*] [$x:expr] $guard:tt) => {
         match $x {
-            $p($($n),+) $(if $guard)? => ::core::option::Option::Some(($($n),+)),
+            $($pat)* => {
+                $($pre)*
+                $crate::some!(@when $guard [::core::option::Option::Some(($($($val)*),+))] [::core::option::Option::None])
+            }
             _ => ::core::option::Option::None,
         }
     };
 
-    ( if let $p:path {$($n:ident),+} = $x:expr $(, when $guard:expr)? => $then:expr ) => {
+    (@emit [$($pat:tt)*] [[$($val:tt)*]] $pre:tt $x:tt $guard:tt => $(.$m:ident $(::<$($g:ty),+>)? ($($arg:expr),*))+ $(, or $default:expr)?) => {
+        $crate::some!(@emit [$($pat)*] [[$($val)*]] $pre $x $guard => ($($val)*)$(.$m $(::<$($g),+>)? ($($arg),*))+ $(, or $default)?)
+    };
+
+    (@emit [$($pat:tt)*] [$($val:tt)*] $pre:tt $x:tt $guard:tt => . $($tail:tt)*) => {
+        ::core::compile_error!("a method chain can only be applied to a single captured value")
+    };
+
+    (@emit [$($pat:tt)*] [$($val:tt)*] [$($pre:tt)*] [$x:expr] $guard:tt => $then:expr) => {
         match $x {
-            $p($($n),+) $(if $guard)? => ::core::option::Option::Some($then),
+            $($pat)* => {
+                $($pre)*
+                $crate::some!(@when $guard [::core::option::Option::Some($then)] [::core::option::Option::None])
+            }
             _ => ::core::option::Option::None,
         }
     };
 
-    ( if let $p:path {$($n:ident:),+} = $x:expr $(, when $guard:expr)? ) => {
+    (@emit [$($pat:tt)*] [$($val:tt)*] [$($pre:tt)*] [$x:expr] $guard:tt => $then:expr, or $default:expr) => {
         match $x {
-            $p{$($n),+} $(if $guard)? => ::core::option::Option::Some(($($n),+)),
-            _ => ::core::option::Option::None,
+            $($pat)* => {
+                $($pre)*
+                $crate::some!(@when $guard [$then] [$default])
+            }
+            _ => $default,
         }
     };
+}
 
-    ( if let $p:path {$($n:ident:),+} = $x:expr $(, when $guard:expr)? => $then:expr ) => {
+/// Converts any enum to [`Result`].
+///
+/// Makes an [`Ok`] and an [`Err`] from two selected variants of your enum.
+///
+/// General syntax:
+///
+/// ```man
+/// result!( match <expr> => { Ok: <enum variant> [{ <ident>... }], Err: <enum variant> [{ <ident>... }] [, _ => <fallback expr>] } )
+/// ```
+///
+/// where `<ident>` is `<name> [:]`.
+///
+/// Any other variant evaluates to `<fallback expr>`, which must be a [`Result`] itself.
+/// Without a fallback, the macro panics on any other variant.
+///
+/// # Examples
+///
+/// ```
+/// use option_extra::result;
+///
+/// enum Response {
+///     Data(Vec<u8>),
+///     Failure { code: u16, reason: &'static str },
+///     Pending,
+/// }
+///
+/// let data = Response::Data(vec![1, 2]);
+/// let failure = Response::Failure { code: 404, reason: "not found" };
+///
+/// assert_eq!(
+///     result!(match data => { Ok: Response::Data, Err: Response::Failure { code:, reason: } }),
+///     Ok(vec![1, 2])
+/// );
+/// assert_eq!(
+///     result!(match failure => { Ok: Response::Data, Err: Response::Failure { code:, reason: } }),
+///     Err((404, "not found"))
+/// );
+/// ```
+///
+/// Works with tuple variants:
+///
+/// ```
+/// use option_extra::result;
+///
+/// enum Frame {
+///     Ack(u32, u8),
+///     Nak(u32, &'static str),
+/// }
+///
+/// let ack = Frame::Ack(7, 1);
+///
+/// assert_eq!(result!(match ack => { Ok: Frame::Ack { id, n }, Err: Frame::Nak { id, why } }), Ok((7, 1)));
+/// ```
+///
+/// Other variants use the fallback:
+///
+/// ```
+/// use option_extra::result;
+///
+/// enum Response {
+///     Data(Vec<u8>),
+///     Failure(u16),
+///     Pending,
+/// }
+///
+/// let pending = Response::Pending;
+///
+/// assert_eq!(
+///     result!(match pending => { Ok: Response::Data, Err: Response::Failure, _ => Err(503) }),
+///     Err(503)
+/// );
+/// ```
+///
+/// Or panic without one:
+///
+/// ```should_panic
+/// use option_extra::result;
+///
+/// enum Response {
+///     Data(Vec<u8>),
+///     Failure(u16),
+///     Pending,
+/// }
+///
+/// let pending = Response::Pending;
+///
+/// result!(match pending => { Ok: Response::Data, Err: Response::Failure }); // fails
+/// ```
+///
+/// Both arms are required:
+///
+/// ```compile_fail
+/// use option_extra::result;
+///
+/// enum Response {
+///     Data(Vec<u8>),
+///     Failure(u16),
+/// }
+///
+/// let data = Response::Data(vec![1, 2]);
+///
+/// result!(match data => { Ok: Response::Data });
+/// ```
+#[macro_export]
+macro_rules! result {
+    ( match $x:expr => { Ok: $($rest:tt)* } ) => {
+        $crate::result!(@ok [$x] $($rest)*)
+    };
+
+    (@ok [$($x:tt)*] $p:path {$($n:ident),+}, Err: $($rest:tt)*) => {
+        $crate::result!(@err [$($x)*] [$p($($n),+)] [($($n),+)] $($rest)*)
+    };
+
+    (@ok [$($x:tt)*] $p:path {$($n:ident:),+}, Err: $($rest:tt)*) => {
+        $crate::result!(@err [$($x)*] [$p{$($n),+}] [($($n),+)] $($rest)*)
+    };
+
+    (@ok [$($x:tt)*] $p:path, Err: $($rest:tt)*) => {
+        $crate::result!(@err [$($x)*] [$p(inner)] [inner] $($rest)*)
+    };
+
+    (@err [$($x:tt)*] [$($okp:tt)*] [$($okv:tt)*] $p:path {$($n:ident),+} $(, _ => $fallback:expr)? $(,)?) => {
+        $crate::result!(@emit [$($x)*] [$($okp)*] [$($okv)*] [$p($($n),+)] [($($n),+)] [$($fallback)?])
+    };
+
+    (@err [$($x:tt)*] [$($okp:tt)*] [$($okv:tt)*] $p:path {$($n:ident:),+} $(, _ => $fallback:expr)? $(,)?) => {
+        $crate::result!(@emit [$($x)*] [$($okp)*] [$($okv)*] [$p{$($n),+}] [($($n),+)] [$($fallback)?])
+    };
+
+    (@err [$($x:tt)*] [$($okp:tt)*] [$($okv:tt)*] $p:path $(, _ => $fallback:expr)? $(,)?) => {
+        $crate::result!(@emit [$($x)*] [$($okp)*] [$($okv)*] [$p(inner)] [inner] [$($fallback)?])
+    };
+
+    (@emit [$($x:tt)*] [$($okp:tt)*] [$($okv:tt)*] [$($errp:tt)*] [$($errv:tt)*] []) => {
+        $crate::result!(@emit [$($x)*] [$($okp)*] [$($okv)*] [$($errp)*] [$($errv)*] [
+            ::core::panic!("`result!` matched neither the `Ok` nor the `Err` variant")
+        ])
+    };
+
+    (@emit [$($x:tt)*] [$($okp:tt)*] [$($okv:tt)*] [$($errp:tt)*] [$($errv:tt)*] [$($fallback:tt)+]) => {
+        match $($x)* {
+            $($okp)* => ::core::result::Result::Ok($($okv)*),
+            $($errp)* => ::core::result::Result::Err($($errv)*),
+            #[allow(unreachable_patterns)]
+            _ => $($fallback)+,
+        }
+    };
+}
+
+/// Unwraps an [`Option`] or returns [`None`] early.
+///
+/// Can only be used in functions or closures that return an [`Option`].
+///
+/// General syntax:
+///
+/// ```man
+/// try_some!( <expr> )
+/// try_some!( if let ... )
+/// ```
+///
+/// where the second form accepts anything that [`some!`] does.
+///
+/// # Examples
+///
+/// ```
+/// use option_extra::try_some;
+///
+/// fn first_char_upper(s: &str) -> Option<char> {
+///     let c = try_some!(s.chars().next());
+///     Some(c.to_ascii_uppercase())
+/// }
+///
+/// assert_eq!(first_char_upper("abc"), Some('A'));
+/// assert_eq!(first_char_upper(""), None);
+/// ```
+///
+/// Early-returns when the enum doesn't match the given variant:
+///
+/// ```
+/// use option_extra::try_some;
+///
+/// enum Token {
+///     Number(i32),
+///     Word(String),
+/// }
+///
+/// fn double(t: Token) -> Option<i32> {
+///     let n = try_some!(if let Token::Number = t);
+///     Some(n * 2)
+/// }
+///
+/// assert_eq!(double(Token::Number(4)), Some(8));
+/// assert_eq!(double(Token::Word("four".to_owned())), None);
+/// ```
+///
+/// Works just as well inside closures:
+///
+/// ```
+/// use option_extra::try_some;
+///
+/// let sum = |a: Option<i32>, b: Option<i32>| Some(try_some!(a) + try_some!(b));
+///
+/// assert_eq!(sum(Some(1), Some(2)), Some(3));
+/// assert_eq!(sum(Some(1), None), None);
+/// ```
+#[macro_export]
+macro_rules! try_some {
+    ( if let $($t:tt)+ ) => {
+        match $crate::some!(if let $($t)+) {
+            ::core::option::Option::Some(x) => x,
+            ::core::option::Option::None => return ::core::option::Option::None,
+        }
+    };
+
+    ( $x:expr ) => {
         match $x {
-            $p{$($n),+} $(if $guard)? => ::core::option::Option::Some($then),
-            _ => ::core::option::Option::None,
+            ::core::option::Option::Some(x) => x,
+            ::core::option::Option::None => return ::core::option::Option::None,
         }
     };
 }
@@ -1,3 +1,20 @@
+use core::fmt::{self, Debug};
+use core::iter::{Cycle, Take};
+use core::ops::{Add, AddAssign, Deref, Mul};
+use core::option::IntoIter;
+
+/// The error returned by [`OptionExt::get`] when the value is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NoneError;
+
+impl fmt::Display for NoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a value, found `None`")
+    }
+}
+
+impl core::error::Error for NoneError {}
+
 /// Extra methods for the [`Option`] type.
 pub trait OptionExt<T> {
     /// Like [`Option::zip`], but the other [`Option`] is obtained from `f`
@@ -15,6 +32,7 @@ pub trait OptionExt<T> {
     /// assert_eq!(Some("abc").zip_lazy(none), None);
     /// assert_eq!(None::<i32>.zip_lazy(some), None);
     /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     fn zip_lazy<U, F>(self, f: F) -> Option<(T, U)>
     where
         F: FnOnce() -> Option<U>;
@@ -79,6 +97,8 @@ pub trait OptionExt<T> {
     /// Mutates the wrapped value with the given function
     /// and returns the resulting `self`.
     ///
+    /// See [`update_in_place`](OptionExt::update_in_place) for mutating through a reference instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -86,54 +106,1886 @@ pub trait OptionExt<T> {
     ///
     /// assert_eq!(Some(vec![1, 2, 3]).update(|v| v.push(0)), Some(vec![1, 2, 3, 0]));
     /// ```
+    #[must_use = "if you don't need the returned value, use `update_in_place` instead"]
     fn update<F>(self, f: F) -> Self
     where
         F: FnOnce(&mut T);
-}
 
-impl<T> OptionExt<T> for Option<T> {
-    fn zip_lazy<U, F>(self, f: F) -> Option<(T, U)>
+    /// Increments `counter` if `self` is [`Some`]
+    /// and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut hits = 0;
+    ///
+    /// assert_eq!(Some(1).count_some(&mut hits), Some(1));
+    /// assert_eq!(None::<i32>.count_some(&mut hits), None);
+    /// assert_eq!(hits, 1);
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn count_some(self, counter: &mut usize) -> Self;
+
+    /// Increments `counter` if `self` is [`None`]
+    /// and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut misses = 0;
+    ///
+    /// assert_eq!(Some(1).count_none(&mut misses), Some(1));
+    /// assert_eq!(None::<i32>.count_none(&mut misses), None);
+    /// assert_eq!(misses, 1);
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn count_none(self, counter: &mut usize) -> Self;
+
+    /// Returns the wrapped value or panics if `self` is [`None`].
+    ///
+    /// Same as [`Option::unwrap`], provided for symmetry with [`unwrap_none`](OptionExt::unwrap_none).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some("abc").unwrap_some(), "abc");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::OptionExt;
+    ///
+    /// None::<()>.unwrap_some(); // fails
+    /// ```
+    fn unwrap_some(self) -> T;
+
+    /// Returns the wrapped value or panics with a custom message if `self` is [`None`].
+    ///
+    /// Same as [`Option::expect`], provided for symmetry with [`expect_none`](OptionExt::expect_none).
+    ///
+    /// # Panics
+    ///
+    /// Panics with a custom message if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some("abc").expect_some("expected something"), "abc");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::OptionExt;
+    ///
+    /// None::<()>.expect_some("expected something"); // fails with "expected something"
+    /// ```
+    fn expect_some(self, msg: &str) -> T;
+
+    /// Dereferences the wrapped value, or returns `default` if `self` is [`None`].
+    ///
+    /// Shorthand for `my_option.as_deref().unwrap_or(default)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let name = Some(String::from("abc"));
+    /// let missing = None::<String>;
+    ///
+    /// assert_eq!(name.as_deref_or("unknown"), "abc");
+    /// assert_eq!(missing.as_deref_or("unknown"), "unknown");
+    /// ```
+    fn as_deref_or<'a>(&'a self, default: &'a T::Target) -> &'a T::Target
     where
-        F: FnOnce() -> Option<U>,
-    {
-        let a = self?;
-        let b = f()?;
+        T: Deref;
 
-        Some((a, b))
-    }
+    /// Returns the wrapped value or aborts the process if `self` is [`None`].
+    ///
+    /// Unlike [`Option::unwrap`], this never unwinds, which makes it usable
+    /// where unwinding is not allowed, e.g. in FFI callbacks.
+    /// It never returns if `self` is [`None`], calling [`std::process::abort`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(1).unwrap_or_abort(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn unwrap_or_abort(self) -> T;
 
-    fn satisfies<P>(&self, predicate: P) -> bool
+    /// Adds two optional values, skipping any that are absent.
+    ///
+    /// Returns the sum if both are [`Some`], the single present value
+    /// if only one is, or [`None`] if neither is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(3).sum_with(Some(4)), Some(7));
+    /// assert_eq!(Some(3).sum_with(None), Some(3));
+    /// assert_eq!(None.sum_with(Some(4)), Some(4));
+    /// assert_eq!(None::<i32>.sum_with(None), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn sum_with(self, other: Option<T>) -> Option<T>
     where
-        P: FnOnce(&T) -> bool,
-    {
-        match self {
-            Some(x) => predicate(x),
-            None => false,
-        }
-    }
+        T: Add<Output = T>;
 
-    fn unwrap_none(self) {
-        if self.is_some() {
-            panic!("called `OptionExt::unwrap_none` on a `Some` value");
-        }
-    }
+    /// Multiplies two optional values, skipping any that are absent.
+    ///
+    /// Returns the product if both are [`Some`], the single present value
+    /// if only one is, or [`None`] if neither is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(3).product_with(Some(4)), Some(12));
+    /// assert_eq!(Some(3).product_with(None), Some(3));
+    /// assert_eq!(None.product_with(Some(4)), Some(4));
+    /// assert_eq!(None::<i32>.product_with(None), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn product_with(self, other: Option<T>) -> Option<T>
+    where
+        T: Mul<Output = T>;
 
-    fn expect_none(self, msg: &str) {
-        if self.is_some() {
-            panic!("{}", msg);
-        }
-    }
+    /// Converts `self` into a [`Result`] with a readable error naming
+    /// the missing value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(8080).require("port"), Ok(8080));
+    /// assert_eq!(None::<u16>.require("port"), Err("missing required value: port".to_owned()));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn require(self, name: &str) -> Result<T, String>;
 
-    fn update<F>(self, f: F) -> Self
+    /// Calls `f` with a reference to the wrapped value and returns the result,
+    /// or returns [`None`] if `self` is [`None`].
+    ///
+    /// Shorthand for `my_option.as_ref().and_then(f)`, leaving `self` usable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// use std::collections::HashMap;
+    ///
+    /// let ages = HashMap::from([("alice", 30)]);
+    /// let name = Some("alice");
+    ///
+    /// assert_eq!(name.and_then_ref(|n| ages.get(n).copied()), Some(30));
+    /// assert_eq!(name, Some("alice"));
+    /// assert_eq!(None::<&str>.and_then_ref(|n| ages.get(n).copied()), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn and_then_ref<U, F>(&self, f: F) -> Option<U>
     where
-        F: FnOnce(&mut T),
-    {
-        match self {
-            Some(mut x) => {
-                f(&mut x);
-                Some(x)
-            }
-            None => None,
-        }
+        F: FnOnce(&T) -> Option<U>;
+
+    /// Maps a reference to the wrapped value with `f`,
+    /// or returns [`None`] if `self` is [`None`].
+    ///
+    /// Shorthand for `my_option.as_ref().map(f)`, leaving `self` usable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let name = Some(String::from("abcd"));
+    ///
+    /// assert_eq!(name.map_ref(String::len), Some(4));
+    /// assert_eq!(name, Some(String::from("abcd")));
+    /// assert_eq!(None::<String>.map_ref(String::len), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_ref<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnOnce(&T) -> U;
+
+    /// Like [`zip_lazy`](OptionExt::zip_lazy), but `f` is fallible.
+    ///
+    /// Returns `Ok(None)` without calling `f` if `self` is [`None`],
+    /// otherwise pairs the wrapped value with the result of `f`, propagating its error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let ok = || Ok::<_, &str>(1);
+    /// let err = || Err::<i32, _>("failed");
+    ///
+    /// assert_eq!(Some("abc").zip_try_lazy(ok), Ok(Some(("abc", 1))));
+    /// assert_eq!(Some("abc").zip_try_lazy(err), Err("failed"));
+    /// assert_eq!(None::<&str>.zip_try_lazy(|| -> Result<i32, &str> { unreachable!() }), Ok(None));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn zip_try_lazy<U, E, F>(self, f: F) -> Result<Option<(T, U)>, E>
+    where
+        F: FnOnce() -> Result<U, E>;
+
+    /// Discards the wrapped value, keeping only its presence.
+    ///
+    /// Equivalent to `my_option.map(drop)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some("abc").discard(), Some(()));
+    /// assert_eq!(None::<&str>.discard(), None);
+    /// assert_eq!(Some(1).discard().or(Some("abc").discard()), Some(()));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn discard(self) -> Option<()>;
+
+    /// Returns the wrapped value or panics with a message built by `f`
+    /// if `self` is [`None`].
+    ///
+    /// Unlike [`expect_some`](OptionExt::expect_some), the message
+    /// is only built when it is needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message returned by `f` if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut calls = 0;
+    /// let value = Some(1).expect_some_with(|| {
+    ///     calls += 1;
+    ///     "expected something".to_owned()
+    /// });
+    ///
+    /// assert_eq!(value, 1);
+    /// assert_eq!(calls, 0);
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    /// use std::panic;
+    ///
+    /// let key = 42;
+    /// let err = panic::catch_unwind(|| None::<()>.expect_some_with(|| format!("missing key {key}"))).unwrap_err();
+    ///
+    /// assert_eq!(err.downcast_ref::<String>().unwrap(), "missing key 42");
+    /// ```
+    #[cfg(feature = "std")]
+    fn expect_some_with<F>(self, f: F) -> T
+    where
+        F: FnOnce() -> String;
+
+    /// Returns an iterator yielding the wrapped value `n` times,
+    /// or nothing if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some('x').repeat(3).collect::<Vec<_>>(), ['x', 'x', 'x']);
+    /// assert_eq!(None::<char>.repeat(3).collect::<Vec<_>>(), []);
+    /// ```
+    fn repeat(self, n: usize) -> Take<Cycle<IntoIter<T>>>
+    where
+        T: Clone;
+
+    /// Returns `self` if it is [`Some`], otherwise [`Some`] of the default value.
+    ///
+    /// Unlike [`Option::unwrap_or_default`], this keeps the [`Option`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(5).or_default(), Some(5));
+    /// assert_eq!(None::<i32>.or_default(), Some(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn or_default(self) -> Option<T>
+    where
+        T: Default;
+
+    /// Converts `self` into a [`Result`], with [`NoneError`] if `self` is [`None`].
+    ///
+    /// This allows propagating a missing value with `?` wherever
+    /// an error type implementing [`From<NoneError>`] is expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::{NoneError, OptionExt};
+    ///
+    /// use std::collections::HashMap;
+    /// use std::error::Error;
+    ///
+    /// fn port(config: &HashMap<&str, &str>) -> Result<u16, Box<dyn Error>> {
+    ///     let port = config.get("port").get()?.parse()?;
+    ///     Ok(port)
+    /// }
+    ///
+    /// assert_eq!(port(&HashMap::from([("port", "8080")])).unwrap(), 8080);
+    /// assert!(port(&HashMap::new()).unwrap_err().is::<NoneError>());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn get(self) -> Result<T, NoneError>;
+
+    /// Merges two optional values with `f`, which may itself discard the result.
+    ///
+    /// Returns `f(a, b)` if both are [`Some`], the single present value
+    /// if only one is, or [`None`] if neither is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let agree = |a: i32, b: i32| (a == b).then_some(a);
+    ///
+    /// assert_eq!(Some(1).combine_with(Some(1), agree), Some(1));
+    /// assert_eq!(Some(1).combine_with(Some(2), agree), None);
+    /// assert_eq!(Some(1).combine_with(None, agree), Some(1));
+    /// assert_eq!(None.combine_with(Some(2), agree), Some(2));
+    /// assert_eq!(None.combine_with(None, agree), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn combine_with<F>(self, other: Option<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(T, T) -> Option<T>;
+
+    /// Converts the wrapped value with [`From`].
+    ///
+    /// Shorthand for `my_option.map(Into::into)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(5u8).map_into::<u32>(), Some(5u32));
+    /// assert_eq!(None::<u8>.map_into::<u32>(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_into<U>(self) -> Option<U>
+    where
+        U: From<T>;
+
+    /// Pairs the wrapped value with `key`, which is dropped if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<_, _> = [Some(1).with_tag("a"), None.with_tag("b"), Some(3).with_tag("c")]
+    ///     .into_iter()
+    ///     .flatten()
+    ///     .collect();
+    ///
+    /// assert_eq!(map, HashMap::from([("a", 1), ("c", 3)]));
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// use std::rc::Rc;
+    ///
+    /// let key = Rc::new("b");
+    ///
+    /// assert_eq!(None::<i32>.with_tag(Rc::clone(&key)), None);
+    /// assert_eq!(Rc::strong_count(&key), 1);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn with_tag<K>(self, key: K) -> Option<(K, T)>;
+
+    /// Inserts the value returned by `f` if `self` is [`None`],
+    /// then returns a mutable reference to the wrapped value.
+    ///
+    /// If `f` fails, its error is returned and `self` is left as [`None`],
+    /// so a later call can try again.
+    ///
+    /// A shared reference can be obtained by reborrowing the result.
+    /// Initializing through a shared reference to the [`Option`] itself
+    /// needs interior mutability, e.g. a [`OnceCell`](core::cell::OnceCell), instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut slot = None::<i32>;
+    ///
+    /// assert!(slot.get_or_insert_try(|| "x".parse::<i32>()).is_err());
+    /// assert_eq!(slot, None);
+    ///
+    /// assert_eq!(slot.get_or_insert_try(|| "1".parse::<i32>()), Ok(&mut 1));
+    /// assert_eq!(slot, Some(1));
+    ///
+    /// *slot.get_or_insert_try(|| "2".parse::<i32>()).unwrap() += 10;
+    /// assert_eq!(slot, Some(11));
+    /// ```
+    fn get_or_insert_try<E, F>(&mut self, f: F) -> Result<&mut T, E>
+    where
+        F: FnOnce() -> Result<T, E>;
+
+    /// Returns `self` if the wrapped value does *not* satisfy the given predicate,
+    /// or [`None`] otherwise.
+    ///
+    /// The opposite of [`Option::filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let is_empty = |s: &&str| s.is_empty();
+    ///
+    /// assert_eq!(Some("abc").filter_not(is_empty), Some("abc"));
+    /// assert_eq!(Some("").filter_not(is_empty), None);
+    /// assert_eq!(None.filter_not(is_empty), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn filter_not<P>(self, predicate: P) -> Option<T>
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Checks if `self` is [`Some`] and the wrapped value does *not* satisfy
+    /// the given predicate.
+    ///
+    /// Roughly equivalent to `matches!(&my_option, Some(x) if !predicate(x))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let is_root = |name: &&str| *name == "root";
+    ///
+    /// assert!(Some("alice").is_some_and_not(is_root));
+    /// assert!(!Some("root").is_some_and_not(is_root));
+    /// assert!(!None.is_some_and_not(is_root));
+    /// ```
+    fn is_some_and_not<P>(&self, predicate: P) -> bool
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Consumes `self` and checks if the wrapped value satisfies the given predicate,
+    /// or returns `false` if `self` is [`None`].
+    ///
+    /// Unlike [`satisfies`](OptionExt::satisfies), the predicate takes ownership of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut inbox = Vec::new();
+    ///
+    /// assert!(Some("hello".to_owned()).some_and(|msg| {
+    ///     inbox.push(msg);
+    ///     true
+    /// }));
+    /// assert!(!None::<String>.some_and(|_| true));
+    /// assert_eq!(inbox, ["hello"]);
+    /// ```
+    fn some_and<P>(self, predicate: P) -> bool
+    where
+        P: FnOnce(T) -> bool;
+
+    /// Mutates the wrapped value in place with the given function.
+    ///
+    /// Unlike [`update`](OptionExt::update), this works through a mutable reference
+    /// and doesn't need its result to be reassigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut names = Some(vec!["abc"]);
+    /// names.update_in_place(|v| v.push("def"));
+    ///
+    /// assert_eq!(names, Some(vec!["abc", "def"]));
+    /// ```
+    fn update_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T);
+
+    /// Replaces the wrapped value in place with the result of `f`,
+    /// leaving [`None`] untouched.
+    ///
+    /// Saves writing `my_option = my_option.map(f)`, e.g. for struct fields behind a reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// struct Config {
+    ///     name: Option<String>,
+    /// }
+    ///
+    /// fn shout(config: &mut Config) {
+    ///     config.name.map_in_place(|s| s.to_uppercase());
+    /// }
+    ///
+    /// let mut config = Config { name: Some("abc".to_owned()) };
+    /// shout(&mut config);
+    ///
+    /// assert_eq!(config.name.as_deref(), Some("ABC"));
+    /// ```
+    fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> T;
+
+    /// Replaces the wrapped value in place with the result of `f`,
+    /// which may also clear it. [`None`] is left untouched.
+    ///
+    /// Saves writing `my_option = my_option.and_then(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// struct Counter {
+    ///     remaining: Option<u32>,
+    /// }
+    ///
+    /// fn tick(counter: &mut Counter) {
+    ///     counter.remaining.and_then_in_place(|n| n.checked_sub(1));
+    /// }
+    ///
+    /// let mut counter = Counter { remaining: Some(1) };
+    ///
+    /// tick(&mut counter);
+    /// assert_eq!(counter.remaining, Some(0));
+    ///
+    /// tick(&mut counter);
+    /// assert_eq!(counter.remaining, None);
+    /// ```
+    fn and_then_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> Option<T>;
+
+    /// Returns an iterator over the wrapped value, or over `default` if `self` is [`None`].
+    ///
+    /// The iterator always yields exactly one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let cells: Vec<_> = [Some("a"), None, Some("c")]
+    ///     .into_iter()
+    ///     .flat_map(|cell| cell.iter_or("-"))
+    ///     .collect();
+    ///
+    /// assert_eq!(cells, ["a", "-", "c"]);
+    /// assert_eq!(None.iter_or("-").count(), 1);
+    /// ```
+    fn iter_or(self, default: T) -> IntoIter<T>;
+
+    /// Returns an iterator over the wrapped value, or over the result of `f`
+    /// if `self` is [`None`].
+    ///
+    /// The iterator always yields exactly one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(1).iter_or_else(|| unreachable!()).collect::<Vec<_>>(), [1]);
+    /// assert_eq!(None.iter_or_else(|| 0).collect::<Vec<_>>(), [0]);
+    /// ```
+    fn iter_or_else<F>(self, f: F) -> IntoIter<T>
+    where
+        F: FnOnce() -> T;
+
+    /// Pairs a clone of the wrapped value with each element of `others`,
+    /// or returns an empty [`Vec`] if `self` is [`None`].
+    ///
+    /// Requires `T: Clone`, since the value is cloned once per element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some('x').zip_each(vec![1, 2, 3]), [('x', 1), ('x', 2), ('x', 3)]);
+    /// assert_eq!(None::<char>.zip_each(vec![1, 2, 3]), []);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn zip_each<U>(self, others: Vec<U>) -> Vec<(T, U)>
+    where
+        T: Clone;
+
+    /// Returns [`Ok`] with the wrapped value if it satisfies `predicate`,
+    /// otherwise [`Err`] with `err`.
+    ///
+    /// A [`None`] also results in `Err(err)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(8).filter_or(|x| x % 2 == 0, "odd or missing"), Ok(8));
+    /// assert_eq!(Some(7).filter_or(|x| x % 2 == 0, "odd or missing"), Err("odd or missing"));
+    /// assert_eq!(None.filter_or(|x: &i32| x % 2 == 0, "odd or missing"), Err("odd or missing"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn filter_or<E, P>(self, predicate: P, err: E) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Returns [`Ok`] with the wrapped value if it satisfies `predicate`,
+    /// otherwise [`Err`] with the result of `err`.
+    ///
+    /// `err` is only called when the value is missing or rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(8).filter_or_else(|x| x % 2 == 0, || unreachable!()), Ok::<_, ()>(8));
+    /// assert_eq!(Some(7).filter_or_else(|x| x % 2 == 0, || "odd"), Err("odd"));
+    /// assert_eq!(None.filter_or_else(|x: &i32| x % 2 == 0, || "missing"), Err("missing"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn filter_or_else<E, P, F>(self, predicate: P, err: F) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+        F: FnOnce() -> E;
+
+    /// Like [`filter_or`](OptionExt::filter_or), but with distinct errors
+    /// for a missing value and a value rejected by `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let check = |age: Option<u32>| age.validate(|a| *a >= 18, "missing age", "too young");
+    ///
+    /// assert_eq!(check(Some(30)), Ok(30));
+    /// assert_eq!(check(Some(12)), Err("too young"));
+    /// assert_eq!(check(None), Err("missing age"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn validate<E, P>(self, predicate: P, on_none: E, on_invalid: E) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Ensure that `self` is either [`None`] or `Some(expected)`,
+    /// or panic otherwise, with a custom message.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a custom message if `self` holds a value other than `expected`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// None.expect_none_or_eq(42, "unexpected value");
+    /// Some(42).expect_none_or_eq(42, "unexpected value");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::OptionExt;
+    ///
+    /// Some(7).expect_none_or_eq(42, "unexpected value"); // fails with "unexpected value"
+    /// ```
+    fn expect_none_or_eq(self, expected: T, msg: &str)
+    where
+        T: PartialEq;
+
+    /// Ensure that `self` is [`Some`] and return a reference to the wrapped value,
+    /// without consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let port = Some(8080);
+    ///
+    /// assert_eq!(*port.assert_some(), 8080);
+    /// assert!(port.is_some());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::OptionExt;
+    ///
+    /// None::<u16>.assert_some(); // fails
+    /// ```
+    fn assert_some(&self) -> &T;
+
+    /// Returns `self` if it is [`Some`], otherwise the first [`Some`] in `others`,
+    /// or [`None`] if there is none.
+    ///
+    /// Stops consuming `others` at the first [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(None.coalesce([None, Some(3), Some(4)]), Some(3));
+    /// assert_eq!(Some(1).coalesce([None, Some(3), Some(4)]), Some(1));
+    /// assert_eq!(None::<i32>.coalesce([None, None]), None);
+    ///
+    /// let mut rest = [None, Some(3), Some(4)].into_iter();
+    ///
+    /// assert_eq!(None.coalesce(rest.by_ref()), Some(3));
+    /// assert_eq!(rest.next(), Some(Some(4)));
+    ///
+    /// let mut untouched = [Some(3)].into_iter();
+    ///
+    /// assert_eq!(Some(1).coalesce(untouched.by_ref()), Some(1));
+    /// assert_eq!(untouched.next(), Some(Some(3)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn coalesce<I>(self, others: I) -> Option<T>
+    where
+        I: IntoIterator<Item = Option<T>>;
+
+    /// Returns `self` if it is [`Some`], otherwise calls each closure in `fallbacks`
+    /// in order and returns the first [`Some`] produced.
+    ///
+    /// Closures after the first [`Some`] are never called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let fallbacks: [Box<dyn FnOnce() -> Option<i32>>; 3] = [
+    ///     Box::new(|| None),
+    ///     Box::new(|| Some(2)),
+    ///     Box::new(|| unreachable!()),
+    /// ];
+    ///
+    /// assert_eq!(None.coalesce_with(fallbacks), Some(2));
+    /// assert_eq!(Some(1).coalesce_with([|| unreachable!()]), Some(1));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn coalesce_with<I, F>(self, fallbacks: I) -> Option<T>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce() -> Option<T>;
+
+    /// Passes the whole option to `f` and returns its result.
+    ///
+    /// Useful for routing an option through a free function in the middle of a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// fn describe(name: Option<&str>) -> String {
+    ///     match name {
+    ///         Some(name) => format!("user {}", name),
+    ///         None => "anonymous".to_owned(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(" alice ").map(str::trim).pipe(describe), "user alice");
+    /// assert_eq!(None.map(str::trim).pipe(describe), "anonymous");
+    /// ```
+    fn pipe<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(Option<T>) -> R;
+
+    /// Converts `self` into a [`Result`], with `Err(())` standing for [`None`].
+    ///
+    /// Same as `self.ok_or(())`, but reads as a non-panicking
+    /// [`unwrap`](Option::unwrap) meant for `?` propagation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(1).try_unwrap(), Ok(1));
+    /// assert_eq!(None::<i32>.try_unwrap(), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn try_unwrap(self) -> Result<T, ()>;
+
+    /// Inserts the default value if `self` is [`None`], then applies `f` to the
+    /// contained value and returns a mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut tags: Option<Vec<&str>> = None;
+    ///
+    /// tags.modify_or_default(|t| t.push("new"));
+    /// assert_eq!(tags.modify_or_default(|t| t.push("urgent")).len(), 2);
+    ///
+    /// assert_eq!(tags, Some(vec!["new", "urgent"]));
+    /// ```
+    fn modify_or_default<F>(&mut self, f: F) -> &mut T
+    where
+        T: Default,
+        F: FnOnce(&mut T);
+
+    /// Replaces the wrapped value with [`None`] if it does not satisfy `predicate`.
+    ///
+    /// Like [`Vec::retain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain), but for a single optional slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut even = Some(4);
+    /// let mut odd = Some(3);
+    ///
+    /// even.retain(|x| x % 2 == 0);
+    /// odd.retain(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(even, Some(4));
+    /// assert_eq!(odd, None);
+    /// ```
+    fn retain<P>(&mut self, predicate: P)
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Adds the wrapped value to `acc`, or does nothing if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut total = 0;
+    ///
+    /// for reading in [Some(3), None, Some(4), None] {
+    ///     reading.add_to(&mut total);
+    /// }
+    ///
+    /// assert_eq!(total, 7);
+    /// ```
+    fn add_to(self, acc: &mut T)
+    where
+        T: AddAssign;
+
+    /// Prints the wrapped value to stderr as `<label>: <value>`,
+    /// and returns `self` unchanged.
+    ///
+    /// Only prints in debug builds, i.e. with `debug_assertions` enabled;
+    /// in release builds this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let port = "8080".parse::<u16>().ok().debug_some("port"); // prints "port: 8080"
+    ///
+    /// assert_eq!(port, Some(8080));
+    /// assert_eq!(None::<u16>.debug_some("port"), None); // prints nothing
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "if unused, `self` is dropped"]
+    fn debug_some(self, label: &str) -> Self
+    where
+        T: Debug;
+
+    /// Prints `<label>: None` to stderr if `self` is [`None`],
+    /// and returns `self` unchanged.
+    ///
+    /// Only prints in debug builds, i.e. with `debug_assertions` enabled;
+    /// in release builds this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let port = "x".parse::<u16>().ok().debug_none("port"); // prints "port: None"
+    ///
+    /// assert_eq!(port, None);
+    /// assert_eq!(Some(8080).debug_none("port"), Some(8080)); // prints nothing
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "if unused, `self` is dropped"]
+    fn debug_none(self, label: &str) -> Self;
+
+    /// Zips references to the wrapped values of `self` and `other`,
+    /// without consuming either.
+    ///
+    /// Same as `self.as_ref().zip(other.as_ref())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let name = Some("alice".to_owned());
+    /// let age = Some(30);
+    ///
+    /// assert_eq!(name.zip_ref(&age), Some((&"alice".to_owned(), &30)));
+    /// assert_eq!(name.zip_ref(&None::<u8>), None);
+    ///
+    /// assert_eq!(name.unwrap(), "alice");
+    /// assert_eq!(age.unwrap(), 30);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn zip_ref<'a, U>(&'a self, other: &'a Option<U>) -> Option<(&'a T, &'a U)>;
+
+    /// Returns `f` applied to a reference to the wrapped value,
+    /// or the result of `default` if `self` is [`None`].
+    ///
+    /// Unlike [`Option::map_or_else`], this only borrows `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let name = Some("alice".to_owned());
+    /// let missing = None::<String>;
+    ///
+    /// assert_eq!(name.map_or_else_ref(|| 0, String::len), 5);
+    /// assert_eq!(missing.map_or_else_ref(|| 0, String::len), 0);
+    ///
+    /// assert_eq!(name, Some("alice".to_owned()));
+    /// ```
+    fn map_or_else_ref<U, D, F>(&self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(&T) -> U;
+
+    /// Like [`zip_lazy`](OptionExt::zip_lazy), but `f` receives a reference
+    /// to the wrapped value, so the other [`Option`] can depend on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let users = [(1, "alice"), (2, "bob")];
+    /// let emails = [("alice", "alice@example.com")];
+    ///
+    /// let lookup = |id: u32| {
+    ///     let name = users.iter().find(|(i, _)| *i == id).map(|(_, n)| *n);
+    ///     name.zip_lazy_opt(|n| emails.iter().find(|(m, _)| m == n).map(|(_, e)| *e))
+    /// };
+    ///
+    /// assert_eq!(lookup(1), Some(("alice", "alice@example.com")));
+    /// assert_eq!(lookup(2), None);
+    /// assert_eq!(lookup(3), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn zip_lazy_opt<U, F>(self, f: F) -> Option<(T, U)>
+    where
+        F: FnOnce(&T) -> Option<U>;
+
+    /// Keeps the wrapped value only if it satisfies `predicate`,
+    /// then converts it with [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(200u8).filter_map_into::<u32, _>(|x| *x > 100), Some(200u32));
+    /// assert_eq!(Some(50u8).filter_map_into::<u32, _>(|x| *x > 100), None);
+    /// assert_eq!(None::<u8>.filter_map_into::<u32, _>(|x| *x > 100), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn filter_map_into<U, P>(self, predicate: P) -> Option<U>
+    where
+        U: From<T>,
+        P: FnOnce(&T) -> bool;
+
+    /// Returns a reference to the cached value, computing and storing it
+    /// with `f` first if `self` is [`None`].
+    ///
+    /// Like [`Option::get_or_insert_with`], but the returned reference is immutable,
+    /// as is fitting for memoized values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let mut calls = 0;
+    /// let mut cache = None;
+    ///
+    /// let mut expensive = || {
+    ///     calls += 1;
+    ///     42
+    /// };
+    ///
+    /// assert_eq!(*cache.get_or_recompute(&mut expensive), 42);
+    /// assert_eq!(*cache.get_or_recompute(&mut expensive), 42);
+    /// assert_eq!(calls, 1);
+    /// ```
+    fn get_or_recompute<F>(&mut self, f: F) -> &T
+    where
+        F: FnOnce() -> T;
+
+    /// Returns `f` applied to both wrapped values if `self` and `other` are [`Some`],
+    /// or `default` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(3).zip_map_or(Some(4), 0, |w, h| w * h), 12);
+    /// assert_eq!(Some(3).zip_map_or(None::<i32>, 0, |w, h| w * h), 0);
+    /// assert_eq!(None::<i32>.zip_map_or(Some(4), 0, |w, h| w * h), 0);
+    /// ```
+    fn zip_map_or<U, R, F>(self, other: Option<U>, default: R, f: F) -> R
+    where
+        F: FnOnce(T, U) -> R;
+
+    /// Returns `f` applied to both wrapped values if `self` and `other` are [`Some`],
+    /// or the result of `default` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(3).zip_map_or_else(Some(4), || unreachable!(), |w, h| w * h), 12);
+    /// assert_eq!(Some(3).zip_map_or_else(None::<i32>, || 0, |w, h| w * h), 0);
+    /// assert_eq!(None::<i32>.zip_map_or_else(Some(4), || 0, |w, h| w * h), 0);
+    /// ```
+    fn zip_map_or_else<U, R, D, F>(self, other: Option<U>, default: D, f: F) -> R
+    where
+        D: FnOnce() -> R,
+        F: FnOnce(T, U) -> R;
+
+    /// Returns the result of `f` applied to the wrapped value,
+    /// or `default` if `self` is [`None`].
+    ///
+    /// Like [`Option::and_then`], but with an explicit fallback for [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// let half = |x: i32| (x % 2 == 0).then(|| x / 2);
+    ///
+    /// assert_eq!(Some(4).and_then_or(Some(0), half), Some(2));
+    /// assert_eq!(Some(3).and_then_or(Some(0), half), None);
+    /// assert_eq!(None.and_then_or(Some(0), half), Some(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn and_then_or<U, F>(self, default: Option<U>, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>;
+
+    /// Zips `self` with `other`, using `U::default()` in place of a missing `other`.
+    ///
+    /// Only `self` is required: the result is [`None`] only if `self` is [`None`].
+    /// To default the first side instead, call this on `other` and swap the pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some("port").zip_or_default(Some(8080)), Some(("port", 8080)));
+    /// assert_eq!(Some("port").zip_or_default(None::<u16>), Some(("port", 0)));
+    /// assert_eq!(None::<&str>.zip_or_default(Some(8080)), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn zip_or_default<U>(self, other: Option<U>) -> Option<(T, U)>
+    where
+        U: Default;
+
+    /// Ensure that `self` is [`None`] or panic otherwise,
+    /// with a message showing the unexpected value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`Some`], with a message including the value's [`Debug`] output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// None::<i32>.expect_none_dbg();
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    /// use std::panic;
+    ///
+    /// let err = panic::catch_unwind(|| Some(42).expect_none_dbg()).unwrap_err();
+    ///
+    /// assert_eq!(err.downcast_ref::<String>().unwrap(), "expected None, got Some(42)");
+    /// ```
+    fn expect_none_dbg(self)
+    where
+        T: Debug;
+
+    /// Formats the wrapped value with [`Display`](fmt::Display),
+    /// or returns `placeholder` if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionExt;
+    ///
+    /// assert_eq!(Some(98.6).display_or("N/A"), "98.6");
+    /// assert_eq!(None::<f64>.display_or("N/A"), "N/A");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn display_or(self, placeholder: &str) -> String
+    where
+        T: fmt::Display;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn zip_lazy<U, F>(self, f: F) -> Option<(T, U)>
+    where
+        F: FnOnce() -> Option<U>,
+    {
+        let a = self?;
+        let b = f()?;
+
+        Some((a, b))
+    }
+
+    fn satisfies<P>(&self, predicate: P) -> bool
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Some(x) => predicate(x),
+            None => false,
+        }
+    }
+
+    fn unwrap_none(self) {
+        if self.is_some() {
+            panic!("called `OptionExt::unwrap_none` on a `Some` value");
+        }
+    }
+
+    fn expect_none(self, msg: &str) {
+        if self.is_some() {
+            panic!("{}", msg);
+        }
+    }
+
+    fn update<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        match self {
+            Some(mut x) => {
+                f(&mut x);
+                Some(x)
+            }
+            None => None,
+        }
+    }
+
+    fn count_some(self, counter: &mut usize) -> Self {
+        if self.is_some() {
+            *counter += 1;
+        }
+
+        self
+    }
+
+    fn count_none(self, counter: &mut usize) -> Self {
+        if self.is_none() {
+            *counter += 1;
+        }
+
+        self
+    }
+
+    fn unwrap_some(self) -> T {
+        match self {
+            Some(x) => x,
+            None => panic!("called `OptionExt::unwrap_some` on a `None` value"),
+        }
+    }
+
+    fn expect_some(self, msg: &str) -> T {
+        match self {
+            Some(x) => x,
+            None => panic!("{}", msg),
+        }
+    }
+
+    fn as_deref_or<'a>(&'a self, default: &'a T::Target) -> &'a T::Target
+    where
+        T: Deref,
+    {
+        match self {
+            Some(x) => x,
+            None => default,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn unwrap_or_abort(self) -> T {
+        match self {
+            Some(x) => x,
+            None => std::process::abort(),
+        }
+    }
+
+    fn sum_with(self, other: Option<T>) -> Option<T>
+    where
+        T: Add<Output = T>,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn product_with(self, other: Option<T>) -> Option<T>
+    where
+        T: Mul<Output = T>,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a * b),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn require(self, name: &str) -> Result<T, String> {
+        self.ok_or_else(|| format!("missing required value: {name}"))
+    }
+
+    fn and_then_ref<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnOnce(&T) -> Option<U>,
+    {
+        match self {
+            Some(x) => f(x),
+            None => None,
+        }
+    }
+
+    fn map_ref<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        self.as_ref().map(f)
+    }
+
+    fn zip_try_lazy<U, E, F>(self, f: F) -> Result<Option<(T, U)>, E>
+    where
+        F: FnOnce() -> Result<U, E>,
+    {
+        match self {
+            Some(x) => f().map(|u| Some((x, u))),
+            None => Ok(None),
+        }
+    }
+
+    fn discard(self) -> Option<()> {
+        self.map(drop)
+    }
+
+    #[cfg(feature = "std")]
+    fn expect_some_with<F>(self, f: F) -> T
+    where
+        F: FnOnce() -> String,
+    {
+        match self {
+            Some(x) => x,
+            None => panic!("{}", f()),
+        }
+    }
+
+    fn repeat(self, n: usize) -> Take<Cycle<IntoIter<T>>>
+    where
+        T: Clone,
+    {
+        self.into_iter().cycle().take(n)
+    }
+
+    fn or_default(self) -> Option<T>
+    where
+        T: Default,
+    {
+        Some(self.unwrap_or_default())
+    }
+
+    fn get(self) -> Result<T, NoneError> {
+        self.ok_or(NoneError)
+    }
+
+    fn combine_with<F>(self, other: Option<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(T, T) -> Option<T>,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => f(a, b),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn map_into<U>(self) -> Option<U>
+    where
+        U: From<T>,
+    {
+        self.map(U::from)
+    }
+
+    fn with_tag<K>(self, key: K) -> Option<(K, T)> {
+        self.map(|x| (key, x))
+    }
+
+    fn get_or_insert_try<E, F>(&mut self, f: F) -> Result<&mut T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        match self {
+            Some(x) => Ok(x),
+            None => Ok(self.insert(f()?)),
+        }
+    }
+
+    fn filter_not<P>(self, predicate: P) -> Option<T>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        self.filter(|x| !predicate(x))
+    }
+
+    fn is_some_and_not<P>(&self, predicate: P) -> bool
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Some(x) => !predicate(x),
+            None => false,
+        }
+    }
+
+    fn some_and<P>(self, predicate: P) -> bool
+    where
+        P: FnOnce(T) -> bool,
+    {
+        match self {
+            Some(x) => predicate(x),
+            None => false,
+        }
+    }
+
+    fn update_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Some(x) = self {
+            f(x);
+        }
+    }
+
+    fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        if let Some(x) = self.take() {
+            *self = Some(f(x));
+        }
+    }
+
+    fn and_then_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> Option<T>,
+    {
+        if let Some(x) = self.take() {
+            *self = f(x);
+        }
+    }
+
+    fn iter_or(self, default: T) -> IntoIter<T> {
+        Some(self.unwrap_or(default)).into_iter()
+    }
+
+    fn iter_or_else<F>(self, f: F) -> IntoIter<T>
+    where
+        F: FnOnce() -> T,
+    {
+        Some(self.unwrap_or_else(f)).into_iter()
+    }
+
+    #[cfg(feature = "std")]
+    fn zip_each<U>(self, others: Vec<U>) -> Vec<(T, U)>
+    where
+        T: Clone,
+    {
+        match self {
+            Some(x) => others.into_iter().map(|u| (x.clone(), u)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn filter_or<E, P>(self, predicate: P, err: E) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        self.filter(predicate).ok_or(err)
+    }
+
+    fn filter_or_else<E, P, F>(self, predicate: P, err: F) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+        F: FnOnce() -> E,
+    {
+        self.filter(predicate).ok_or_else(err)
+    }
+
+    fn validate<E, P>(self, predicate: P, on_none: E, on_invalid: E) -> Result<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Some(x) if predicate(&x) => Ok(x),
+            Some(_) => Err(on_invalid),
+            None => Err(on_none),
+        }
+    }
+
+    fn expect_none_or_eq(self, expected: T, msg: &str)
+    where
+        T: PartialEq,
+    {
+        if matches!(self, Some(x) if x != expected) {
+            panic!("{}", msg);
+        }
+    }
+
+    fn assert_some(&self) -> &T {
+        match self {
+            Some(x) => x,
+            None => panic!("called `OptionExt::assert_some` on a `None` value"),
+        }
+    }
+
+    fn coalesce<I>(self, others: I) -> Option<T>
+    where
+        I: IntoIterator<Item = Option<T>>,
+    {
+        self.or_else(|| others.into_iter().flatten().next())
+    }
+
+    fn coalesce_with<I, F>(self, fallbacks: I) -> Option<T>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce() -> Option<T>,
+    {
+        self.or_else(|| fallbacks.into_iter().find_map(|f| f()))
+    }
+
+    fn pipe<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(Option<T>) -> R,
+    {
+        f(self)
+    }
+
+    fn try_unwrap(self) -> Result<T, ()> {
+        self.ok_or(())
+    }
+
+    fn modify_or_default<F>(&mut self, f: F) -> &mut T
+    where
+        T: Default,
+        F: FnOnce(&mut T),
+    {
+        let x = self.get_or_insert_with(T::default);
+        f(x);
+        x
+    }
+
+    fn retain<P>(&mut self, predicate: P)
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        if matches!(self, Some(x) if !predicate(x)) {
+            *self = None;
+        }
+    }
+
+    fn add_to(self, acc: &mut T)
+    where
+        T: AddAssign,
+    {
+        if let Some(x) = self {
+            *acc += x;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn debug_some(self, label: &str) -> Self
+    where
+        T: Debug,
+    {
+        if cfg!(debug_assertions) {
+            if let Some(x) = &self {
+                eprintln!("{label}: {x:?}");
+            }
+        }
+
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn debug_none(self, label: &str) -> Self {
+        if cfg!(debug_assertions) && self.is_none() {
+            eprintln!("{label}: None");
+        }
+
+        self
+    }
+
+    fn zip_ref<'a, U>(&'a self, other: &'a Option<U>) -> Option<(&'a T, &'a U)> {
+        self.as_ref().zip(other.as_ref())
+    }
+
+    fn map_or_else_ref<U, D, F>(&self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(&T) -> U,
+    {
+        match self {
+            Some(x) => f(x),
+            None => default(),
+        }
+    }
+
+    fn zip_lazy_opt<U, F>(self, f: F) -> Option<(T, U)>
+    where
+        F: FnOnce(&T) -> Option<U>,
+    {
+        let a = self?;
+        let b = f(&a)?;
+
+        Some((a, b))
+    }
+
+    fn filter_map_into<U, P>(self, predicate: P) -> Option<U>
+    where
+        U: From<T>,
+        P: FnOnce(&T) -> bool,
+    {
+        self.filter(predicate).map(U::from)
+    }
+
+    fn get_or_recompute<F>(&mut self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        self.get_or_insert_with(f)
+    }
+
+    fn zip_map_or<U, R, F>(self, other: Option<U>, default: R, f: F) -> R
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => f(a, b),
+            _ => default,
+        }
+    }
+
+    fn zip_map_or_else<U, R, D, F>(self, other: Option<U>, default: D, f: F) -> R
+    where
+        D: FnOnce() -> R,
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => f(a, b),
+            _ => default(),
+        }
+    }
+
+    fn and_then_or<U, F>(self, default: Option<U>, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        match self {
+            Some(x) => f(x),
+            None => default,
+        }
+    }
+
+    fn zip_or_default<U>(self, other: Option<U>) -> Option<(T, U)>
+    where
+        U: Default,
+    {
+        self.map(|x| (x, other.unwrap_or_default()))
+    }
+
+    fn expect_none_dbg(self)
+    where
+        T: Debug,
+    {
+        if self.is_some() {
+            panic!("expected None, got {:?}", self);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn display_or(self, placeholder: &str) -> String
+    where
+        T: fmt::Display,
+    {
+        match self {
+            Some(x) => x.to_string(),
+            None => placeholder.to_owned(),
+        }
+    }
+}
+
+/// Extra methods for [`Option`]s wrapping a [`Result`].
+pub trait OptionResultExt<T, E> {
+    /// Collapses `self` into a plain [`Result`],
+    /// using `on_none` as the error if `self` is [`None`].
+    ///
+    /// A wrapped error is converted with [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError {
+    ///     Missing,
+    ///     Invalid(String),
+    /// }
+    ///
+    /// impl From<std::num::ParseIntError> for AppError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         AppError::Invalid(e.to_string())
+    ///     }
+    /// }
+    ///
+    /// let parse = |s: Option<&str>| s.map(str::parse::<i32>).transpose_or(AppError::Missing);
+    ///
+    /// assert_eq!(parse(Some("1")), Ok(1));
+    /// assert_eq!(
+    ///     parse(Some("x")),
+    ///     Err(AppError::Invalid("invalid digit found in string".to_owned()))
+    /// );
+    /// assert_eq!(parse(None), Err(AppError::Missing));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn transpose_or<E2>(self, on_none: E2) -> Result<T, E2>
+    where
+        E2: From<E>;
+}
+
+impl<T, E> OptionResultExt<T, E> for Option<Result<T, E>> {
+    fn transpose_or<E2>(self, on_none: E2) -> Result<T, E2>
+    where
+        E2: From<E>,
+    {
+        match self {
+            Some(Ok(x)) => Ok(x),
+            Some(Err(e)) => Err(E2::from(e)),
+            None => Err(on_none),
+        }
+    }
+}
+
+/// Extra methods for [`Option`]s wrapping a reference.
+pub trait OptionRefExt<T> {
+    /// Clones the referenced value and converts it with [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionRefExt;
+    ///
+    /// let names = ["alice", "bob"];
+    ///
+    /// assert_eq!(names.first().cloned_into::<String>(), Some("alice".to_owned()));
+    /// assert_eq!(names.get(2).cloned_into::<String>(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn cloned_into<U>(self) -> Option<U>
+    where
+        T: Clone,
+        U: From<T>;
+
+    /// Copies the referenced value, or returns `default` if `self` is [`None`].
+    ///
+    /// Same as `self.copied().unwrap_or(default)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionRefExt;
+    ///
+    /// let timeouts = [30, 60];
+    ///
+    /// assert_eq!(timeouts.first().copied_or(10), 30);
+    /// assert_eq!(timeouts.get(2).copied_or(10), 10);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn copied_or(self, default: T) -> T
+    where
+        T: Copy;
+}
+
+impl<T> OptionRefExt<T> for Option<&T> {
+    fn cloned_into<U>(self) -> Option<U>
+    where
+        T: Clone,
+        U: From<T>,
+    {
+        self.cloned().map(U::from)
+    }
+
+    fn copied_or(self, default: T) -> T
+    where
+        T: Copy,
+    {
+        self.copied().unwrap_or(default)
+    }
+}
+
+/// Extra methods for [`Option`]s wrapping a [`Vec`].
+#[cfg(feature = "std")]
+pub trait OptionVecExt<T> {
+    /// Returns the wrapped [`Vec`], or an empty one if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionVecExt;
+    ///
+    /// assert_eq!(Some(vec![1, 2]).flatten_vec(), [1, 2]);
+    /// assert_eq!(None::<Vec<i32>>.flatten_vec(), []);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn flatten_vec(self) -> Vec<T>;
+}
+
+#[cfg(feature = "std")]
+impl<T> OptionVecExt<T> for Option<Vec<T>> {
+    fn flatten_vec(self) -> Vec<T> {
+        self.unwrap_or_default()
+    }
+}
+
+/// Extra methods for [`Option`]s wrapping a [`bool`].
+pub trait OptionBoolExt {
+    /// Returns `true` only if `self` is `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionBoolExt;
+    ///
+    /// assert!(Some(true).is_true());
+    /// assert!(!Some(false).is_true());
+    /// assert!(!None.is_true());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_true(self) -> bool;
+
+    /// Returns `true` only if `self` is `Some(false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionBoolExt;
+    ///
+    /// assert!(!Some(true).is_false());
+    /// assert!(Some(false).is_false());
+    /// assert!(!None.is_false());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_false(self) -> bool;
+
+    /// Returns the wrapped value, or `false` if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::OptionBoolExt;
+    ///
+    /// assert!(Some(true).unwrap_or_false());
+    /// assert!(!Some(false).unwrap_or_false());
+    /// assert!(!None.unwrap_or_false());
+    /// ```
+    fn unwrap_or_false(self) -> bool;
+}
+
+impl OptionBoolExt for Option<bool> {
+    fn is_true(self) -> bool {
+        self == Some(true)
+    }
+
+    fn is_false(self) -> bool {
+        self == Some(false)
+    }
+
+    fn unwrap_or_false(self) -> bool {
+        self.unwrap_or(false)
     }
 }
@@ -1,36 +1,802 @@
+use core::fmt::{Debug, Display};
+use core::option::IntoIter;
+use core::slice;
+
+#[cfg(feature = "std")]
+use std::io::Write;
+
+#[cfg(feature = "std")]
+use crate::{ContextChain, IntoContextChain};
+
 /// Extra methods for the [`Result`] type.
 pub trait ResultExt<T, E> {
     /// If `self` is [`Ok`], checks if the wrapped value satisfies the given predicate.
     /// Otherwise, returns `false`.
     ///
-    /// Roughly equivalent to `matches!(&my_result, Ok(x) if predicate(x))`.
+    /// Roughly equivalent to `matches!(&my_result, Ok(x) if predicate(x))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert!(Ok::<_, ()>(1).satisfies(|&n| n % 2 == 1));
+    /// ```
+    fn satisfies<P>(&self, predicate: P) -> bool
+    where
+        P: FnOnce(&T) -> bool;
+
+    /// Mutates the wrapped `Ok` value with the given function
+    /// and returns the resulting `self`.
+    ///
+    /// See [`update_in_place`](ResultExt::update_in_place) for mutating through a reference instead.
+    ///
+    /// An `Err` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, ()>(vec![1, 2, 3]).update(|v| v.push(0)), Ok(vec![1, 2, 3, 0]));
+    /// ```
+    #[must_use = "if you don't need the returned value, use `update_in_place` instead"]
+    fn update<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T);
+
+    /// Increments `counter` if `self` is [`Ok`]
+    /// and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let mut oks = 0;
+    ///
+    /// assert_eq!(Ok::<_, ()>(1).count_ok(&mut oks), Ok(1));
+    /// assert_eq!(Err::<i32, _>("abc").count_ok(&mut oks), Err("abc"));
+    /// assert_eq!(oks, 1);
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn count_ok(self, counter: &mut usize) -> Self;
+
+    /// Increments `counter` if `self` is [`Err`]
+    /// and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let mut errs = 0;
+    ///
+    /// assert_eq!(Ok::<_, ()>(1).count_err(&mut errs), Ok(1));
+    /// assert_eq!(Err::<i32, _>("abc").count_err(&mut errs), Err("abc"));
+    /// assert_eq!(errs, 1);
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn count_err(self, counter: &mut usize) -> Self;
+
+    /// Maps the wrapped `Ok` value with `f`, or converts the `Err` value
+    /// into the same type via [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Outcome {
+    ///     Parsed(i32),
+    ///     Failed(String),
+    /// }
+    ///
+    /// impl From<String> for Outcome {
+    ///     fn from(e: String) -> Self {
+    ///         Outcome::Failed(e)
+    ///     }
+    /// }
+    ///
+    /// let ok: Result<i32, String> = Ok(5);
+    /// let err: Result<i32, String> = Err("bad input".to_owned());
+    ///
+    /// assert_eq!(ok.ok_or_err_value(Outcome::Parsed), Outcome::Parsed(5));
+    /// assert_eq!(err.ok_or_err_value(Outcome::Parsed), Outcome::Failed("bad input".to_owned()));
+    /// ```
+    fn ok_or_err_value<U, F>(self, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+        U: From<E>;
+
+    /// Returns the wrapped `Ok` value or panics with `context`
+    /// followed by the [`Display`] of the error.
+    ///
+    /// Like [`Result::expect`], but uses [`Display`] instead of [`Debug`](core::fmt::Debug).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, String>(1).require("reading config"), 1);
+    /// ```
+    ///
+    /// The panic message combines the context with the error:
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    /// use std::panic;
+    ///
+    /// let err = panic::catch_unwind(|| Err::<(), _>("file not found").require("reading config")).unwrap_err();
+    ///
+    /// assert_eq!(err.downcast_ref::<String>().unwrap(), "reading config: file not found");
+    /// ```
+    fn require(self, context: &str) -> T
+    where
+        E: Display;
+
+    /// Calls `f` with a reference to the wrapped `Ok` value and returns the result,
+    /// or returns a clone of the error if `self` is [`Err`].
+    ///
+    /// The error has to be [`Clone`], because `self` is only borrowed.
+    /// See [`and_then_ref_borrowed`](ResultExt::and_then_ref_borrowed)
+    /// for a version that borrows the error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let ok: Result<&str, String> = Ok("42");
+    /// let err: Result<&str, String> = Err("no input".to_owned());
+    ///
+    /// let parse = |s: &&str| s.parse::<i32>().map_err(|e| e.to_string());
+    ///
+    /// assert_eq!(ok.and_then_ref(parse), Ok(42));
+    /// assert_eq!(err.and_then_ref(parse), Err("no input".to_owned()));
+    /// assert_eq!(ok, Ok("42"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn and_then_ref<U, F>(&self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(&T) -> Result<U, E>,
+        E: Clone;
+
+    /// Calls `f` with a reference to the wrapped `Ok` value and returns the result,
+    /// or returns a reference to the error if `self` is [`Err`].
+    ///
+    /// Shorthand for `my_result.as_ref().and_then(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// const TOO_BIG: &str = "too big";
+    ///
+    /// let small: Result<i32, &str> = Ok(1);
+    /// let big: Result<i32, &str> = Ok(100);
+    /// let err: Result<i32, &str> = Err("no input");
+    ///
+    /// let check = |&n: &i32| if n < 10 { Ok(n * 2) } else { Err(&TOO_BIG) };
+    ///
+    /// assert_eq!(small.and_then_ref_borrowed(check), Ok(2));
+    /// assert_eq!(big.and_then_ref_borrowed(check), Err(&"too big"));
+    /// assert_eq!(err.and_then_ref_borrowed(check), Err(&"no input"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn and_then_ref_borrowed<'a, U, F>(&'a self, f: F) -> Result<U, &'a E>
+    where
+        T: 'a,
+        E: 'a,
+        F: FnOnce(&'a T) -> Result<U, &'a E>;
+
+    /// Discards the wrapped `Ok` value, keeping only the error.
+    ///
+    /// Equivalent to `my_result.map(drop)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).discard_ok(), Ok(()));
+    /// assert_eq!(Err::<i32, _>("abc").discard_ok(), Err("abc"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn discard_ok(self) -> Result<(), E>;
+
+    /// Discards the wrapped `Err` value, keeping only the success.
+    ///
+    /// Equivalent to `my_result.map_err(drop)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).discard_err(), Ok(1));
+    /// assert_eq!(Err::<i32, _>("abc").discard_err(), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn discard_err(self) -> Result<T, ()>;
+
+    /// Returns the wrapped `Err` value or panics with a message built by `f`
+    /// from the unexpected `Ok` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message returned by `f` if `self` is [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let parsed = "abc".parse::<i32>();
+    ///
+    /// assert!(parsed.expect_err_with(|n| format!("parsed into {n}")).to_string().contains("invalid digit"));
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    /// use std::panic;
+    ///
+    /// let err = panic::catch_unwind(|| "42".parse::<i32>().expect_err_with(|n| format!("parsed into {n}"))).unwrap_err();
+    ///
+    /// assert_eq!(err.downcast_ref::<String>().unwrap(), "parsed into 42");
+    /// ```
+    #[cfg(feature = "std")]
+    fn expect_err_with<F>(self, f: F) -> E
+    where
+        F: FnOnce(&T) -> String;
+
+    /// Calls `f` with a borrowed view of `self`, whichever variant it is,
+    /// and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let mut log = Vec::new();
+    /// let mut record = |r: Result<&i32, &&str>| match r {
+    ///     Ok(n) => log.push(format!("got {n}")),
+    ///     Err(e) => log.push(format!("failed: {e}")),
+    /// };
+    ///
+    /// assert_eq!(Ok(1).tap(&mut record), Ok(1));
+    /// assert_eq!(Err("abc").tap(&mut record), Err("abc"));
+    /// assert_eq!(log, ["got 1", "failed: abc"]);
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn tap<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Result<&T, &E>);
+
+    /// Wraps the error in a [`ContextChain`] with `ctx` on top.
+    ///
+    /// Chaining onto a [`ContextChain`] adds another line to it,
+    /// so nested calls build up a stack of context, outermost first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let err = Err::<(), _>("not found").chain_context("reading file");
+    ///
+    /// assert_eq!(err.unwrap_err().into_vec(), ["reading file", "not found"]);
+    /// ```
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let err = Err::<(), _>("not found")
+    ///     .chain_context("reading file")
+    ///     .chain_context("loading config");
+    ///
+    /// assert_eq!(err.unwrap_err().lines(), ["loading config", "reading file", "not found"]);
+    /// assert_eq!(Ok::<_, &str>(1).chain_context("reading file"), Ok(1));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn chain_context<C>(self, ctx: C) -> Result<T, ContextChain>
+    where
+        C: Display,
+        E: IntoContextChain;
+
+    /// Converts the wrapped `Ok` value with [`From`].
+    ///
+    /// Shorthand for `my_result.map(Into::into)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, ()>(5u8).map_into::<u32>(), Ok(5u32));
+    /// assert_eq!(Err::<u8, _>("abc").map_into::<u32>(), Err("abc"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_into<U>(self) -> Result<U, E>
+    where
+        U: From<T>;
+
+    /// Converts the wrapped `Err` value with [`From`],
+    /// just like `?` would, but without returning early.
+    ///
+    /// Shorthand for `my_result.map_err(Into::into)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct AppError(String);
+    ///
+    /// impl From<std::num::ParseIntError> for AppError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         AppError(e.to_string())
+    ///     }
+    /// }
+    ///
+    /// assert_eq!("1".parse::<i32>().map_err_into::<AppError>(), Ok(1));
+    /// assert_eq!(
+    ///     "x".parse::<i32>().map_err_into::<AppError>(),
+    ///     Err(AppError("invalid digit found in string".to_owned()))
+    /// );
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_err_into<E2>(self) -> Result<T, E2>
+    where
+        E2: From<E>;
+
+    /// Replaces the wrapped `Err` value with the default value of another error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// enum ApiError {
+    ///     #[default]
+    ///     Internal,
+    ///     NotFound,
+    /// }
+    ///
+    /// assert_eq!("1".parse::<i32>().map_err_default::<ApiError>(), Ok(1));
+    /// assert_eq!("x".parse::<i32>().map_err_default::<ApiError>(), Err(ApiError::Internal));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_err_default<E2>(self) -> Result<T, E2>
+    where
+        E2: Default;
+
+    /// Consumes `self` and checks if the wrapped `Ok` value satisfies the given predicate.
+    /// Returns `false` if `self` is [`Err`].
+    ///
+    /// Unlike [`satisfies`](ResultExt::satisfies), the predicate takes ownership of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let seen = vec!["abc".to_owned()];
+    /// let is_seen = |s: String| seen.contains(&s);
+    ///
+    /// assert!(Ok::<_, ()>("abc".to_owned()).ok_and(is_seen));
+    /// assert!(!Ok::<_, ()>("xyz".to_owned()).ok_and(is_seen));
+    /// assert!(!Err(()).ok_and(is_seen));
+    /// ```
+    fn ok_and<P>(self, predicate: P) -> bool
+    where
+        P: FnOnce(T) -> bool;
+
+    /// Mutates the wrapped `Ok` value in place with the given function.
+    ///
+    /// Unlike [`update`](ResultExt::update), this works through a mutable reference
+    /// and doesn't need its result to be reassigned. An `Err` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// struct Job {
+    ///     output: Result<String, ()>,
+    /// }
+    ///
+    /// let mut job = Job { output: Ok("abc".to_owned()) };
+    /// job.output.update_in_place(|s| s.push('!'));
+    ///
+    /// assert_eq!(job.output, Ok("abc!".to_owned()));
+    /// ```
+    fn update_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T);
+
+    /// Returns an iterator over the wrapped `Ok` value, which is empty if `self` is [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let results = vec![Ok(1), Err("a"), Ok(3)];
+    /// let oks: Vec<_> = results.into_iter().flat_map(ResultExt::ok_iter).collect();
+    ///
+    /// assert_eq!(oks, [1, 3]);
+    /// ```
+    fn ok_iter(self) -> IntoIter<T>;
+
+    /// Returns an iterator over the wrapped `Err` value, which is empty if `self` is [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let results = vec![Ok(1), Err("a"), Ok(3)];
+    /// let errs: Vec<_> = results.into_iter().flat_map(ResultExt::err_iter).collect();
+    ///
+    /// assert_eq!(errs, ["a"]);
+    /// ```
+    fn err_iter(self) -> IntoIter<E>;
+
+    /// Maps the success value with `ok` or the error with `err`,
+    /// changing both types in a single call.
+    ///
+    /// Equivalent to `self.map(ok).map_err(err)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let parse = |s: &str| s.parse::<u8>().map_both(u32::from, |e| e.to_string());
+    ///
+    /// assert_eq!(parse("42"), Ok(42u32));
+    /// assert_eq!(parse("x"), Err("invalid digit found in string".to_owned()));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_both<U, F2, F, G>(self, ok: F, err: G) -> Result<U, F2>
+    where
+        F: FnOnce(T) -> U,
+        G: FnOnce(E) -> F2;
+
+    /// Returns the success or error value, converted into a common type `U`.
+    ///
+    /// Both `T: Into<U>` and `E: Into<U>` are required,
+    /// so that either arm can produce the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let ok: Result<&str, char> = Ok("done");
+    /// let err: Result<&str, char> = Err('!');
+    ///
+    /// assert_eq!(ok.into_inner::<String>(), "done");
+    /// assert_eq!(err.into_inner::<String>(), "!");
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn into_inner<U>(self) -> U
+    where
+        T: Into<U>,
+        E: Into<U>;
+
+    /// Ensure that `self` is [`Ok`] and return a reference to the success value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`Err`], with a message including the error's [`Debug`] output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let res: Result<Vec<i32>, ()> = Ok(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(res.assert_ok().len(), 3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::ResultExt;
+    ///
+    /// "x".parse::<i32>().assert_ok(); // fails with the `ParseIntError`
+    /// ```
+    fn assert_ok(&self) -> &T
+    where
+        E: Debug;
+
+    /// Ensure that `self` is [`Err`] and return a reference to the error value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`Ok`], with a message including the value's [`Debug`] output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let res: Result<(), &str> = Err("not found");
+    ///
+    /// assert!(res.assert_err().starts_with("not"));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use option_extra::ResultExt;
+    ///
+    /// "1".parse::<i32>().assert_err(); // fails with `1`
+    /// ```
+    fn assert_err(&self) -> &E
+    where
+        T: Debug;
+
+    /// Returns `self` if it is [`Ok`], otherwise calls each closure in `fallbacks`
+    /// in order and returns the first [`Ok`] produced.
+    ///
+    /// If every fallback fails, the last error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let sources: [fn() -> Result<&'static str, &'static str>; 3] = [
+    ///     || Err("cache miss"),
+    ///     || Ok("from disk"),
+    ///     || unreachable!(),
+    /// ];
+    ///
+    /// assert_eq!(Err("no env var").or_first_ok(sources), Ok("from disk"));
+    ///
+    /// let failing: [fn() -> Result<(), &'static str>; 2] = [|| Err("cache miss"), || Err("no file")];
+    ///
+    /// assert_eq!(Err("no env var").or_first_ok(failing), Err("no file"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn or_first_ok<I, F>(self, fallbacks: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce() -> Self;
+
+    /// Passes the whole result to `f` and returns its output.
+    ///
+    /// Useful for routing a result through a free function in the middle of a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// fn status_code<T, E>(res: Result<T, E>) -> u16 {
+    ///     match res {
+    ///         Ok(_) => 200,
+    ///         Err(_) => 400,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!("42".parse::<u32>().map(|n| n * 2).pipe(status_code), 200);
+    /// assert_eq!("x".parse::<u32>().map(|n| n * 2).pipe(status_code), 400);
+    /// ```
+    fn pipe<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(Result<T, E>) -> R;
+
+    /// Returns a slice of the success value, which is empty if `self` is [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).ok_slice(), [1]);
+    /// assert_eq!(Err::<i32, _>("abc").ok_slice().len(), 0);
+    /// ```
+    fn ok_slice(&self) -> &[T];
+
+    /// Returns a slice of the error value, which is empty if `self` is [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!(Err::<i32, _>("abc").err_slice(), ["abc"]);
+    /// assert_eq!(Ok::<_, &str>(1).err_slice().len(), 0);
+    /// ```
+    fn err_slice(&self) -> &[E];
+
+    /// Returns `f` applied to a reference to the success value,
+    /// or `default` applied to a reference to the error.
+    ///
+    /// Unlike [`Result::map_or_else`], this only borrows `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let ok: Result<String, String> = Ok("alice".to_owned());
+    /// let err: Result<String, String> = Err("not found".to_owned());
+    ///
+    /// assert_eq!(ok.map_or_else_ref(|e| e.len() * 10, String::len), 5);
+    /// assert_eq!(err.map_or_else_ref(|e| e.len() * 10, String::len), 90);
+    ///
+    /// assert_eq!(ok, Ok("alice".to_owned()));
+    /// ```
+    fn map_or_else_ref<U, D, F>(&self, default: D, f: F) -> U
+    where
+        D: FnOnce(&E) -> U,
+        F: FnOnce(&T) -> U;
+
+    /// Calls `ok` with the success value or `err` with the error,
+    /// both returning a [`Result`] of the same type.
+    ///
+    /// This allows the error branch to recover into [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let parse = |s: &str| {
+    ///     s.parse::<i64>().and_then_both(
+    ///         |n| u32::try_from(n).map_err(|_| "out of range"),
+    ///         |_| if s.is_empty() { Ok(0) } else { Err("not a number") },
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(parse("42"), Ok(42));
+    /// assert_eq!(parse("-1"), Err("out of range"));
+    /// assert_eq!(parse(""), Ok(0));
+    /// assert_eq!(parse("x"), Err("not a number"));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn and_then_both<U, F2, F, G>(self, ok: F, err: G) -> Result<U, F2>
+    where
+        F: FnOnce(T) -> Result<U, F2>,
+        G: FnOnce(E) -> Result<U, F2>;
+
+    /// Converts the error into a [`String`] prefixed with context derived from it,
+    /// as `<context>: <error>`.
     ///
     /// # Examples
     ///
     /// ```
     /// use option_extra::ResultExt;
+    /// use std::fmt;
     ///
-    /// assert!(Ok::<_, ()>(1).satisfies(|&n| n % 2 == 1));
+    /// struct HttpError {
+    ///     status: u16,
+    ///     reason: &'static str,
+    /// }
+    ///
+    /// impl fmt::Display for HttpError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{} {}", self.status, self.reason)
+    ///     }
+    /// }
+    ///
+    /// let res: Result<(), _> = Err(HttpError { status: 503, reason: "Service Unavailable" });
+    /// let described = res.map_err_context(|e| if e.status >= 500 { "server error" } else { "client error" });
+    ///
+    /// assert_eq!(described, Err("server error: 503 Service Unavailable".to_owned()));
     /// ```
-    fn satisfies<P>(&self, predicate: P) -> bool
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn map_err_context<C, F>(self, f: F) -> Result<T, String>
     where
-        P: FnOnce(&T) -> bool;
+        E: Display,
+        F: FnOnce(&E) -> C,
+        C: Display;
 
-    /// Mutates the wrapped `Ok` value with the given function
-    /// and returns the resulting `self`.
+    /// Increments `ok` if `self` is [`Ok`] or `err` if it is [`Err`],
+    /// and returns `self` unchanged.
     ///
-    /// An `Err` is returned unchanged.
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let (mut oks, mut errs) = (0, 0);
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).count_into(&mut oks, &mut errs), Ok(1));
+    /// assert_eq!((oks, errs), (1, 0));
+    ///
+    /// assert_eq!(Err::<i32, _>("abc").count_into(&mut oks, &mut errs), Err("abc"));
+    /// assert_eq!((oks, errs), (1, 1));
+    /// ```
+    #[must_use = "if unused, `self` is dropped"]
+    fn count_into(self, ok: &mut usize, err: &mut usize) -> Self;
+
+    /// Returns the success value if it satisfies `predicate`, or [`Err`] with `err` otherwise.
+    ///
+    /// An existing error is converted with [`Into`] instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use option_extra::ResultExt;
     ///
-    /// assert_eq!(Ok::<_, ()>(vec![1, 2, 3]).update(|v| v.push(0)), Ok(vec![1, 2, 3, 0]));
+    /// #[derive(Debug, PartialEq)]
+    /// enum PortError {
+    ///     Parse(std::num::ParseIntError),
+    ///     Reserved,
+    /// }
+    ///
+    /// impl From<std::num::ParseIntError> for PortError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         PortError::Parse(e)
+    ///     }
+    /// }
+    ///
+    /// let port = |s: &str| s.parse::<u16>().ok_filter_or(|p| *p >= 1024, PortError::Reserved);
+    ///
+    /// assert!(matches!(port("x"), Err(PortError::Parse(_))));
+    /// assert_eq!(port("80"), Err(PortError::Reserved));
+    /// assert_eq!(port("8080"), Ok(8080));
     /// ```
-    fn update<F>(self, f: F) -> Self
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ok_filter_or<E2, P>(self, predicate: P, err: E2) -> Result<T, E2>
     where
-        F: FnOnce(&mut T);
+        P: FnOnce(&T) -> bool,
+        E: Into<E2>;
+
+    /// Formats whichever value `self` holds with [`Display`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// assert_eq!("42".parse::<i32>().display_either(), "42");
+    /// assert_eq!("x".parse::<i32>().display_either(), "invalid digit found in string");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn display_either(self) -> String
+    where
+        T: Display,
+        E: Display;
+
+    /// Converts `self` into an [`Option`], writing the error with a trailing newline
+    /// to `w` if `self` is [`Err`].
+    ///
+    /// Failures to write are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultExt;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// assert_eq!("1".parse::<i32>().ok_or_write(&mut log), Some(1));
+    /// assert_eq!("x".parse::<i32>().ok_or_write(&mut log), None);
+    /// assert_eq!(log, b"invalid digit found in string\n");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ok_or_write<W>(self, w: &mut W) -> Option<T>
+    where
+        W: Write,
+        E: Display;
 }
 
 impl<T, E> ResultExt<T, E> for Result<T, E> {
@@ -56,4 +822,414 @@ impl<T, E> ResultExt<T, E> for Result<T, E> {
             err => err,
         }
     }
+
+    fn count_ok(self, counter: &mut usize) -> Self {
+        if self.is_ok() {
+            *counter += 1;
+        }
+
+        self
+    }
+
+    fn count_err(self, counter: &mut usize) -> Self {
+        if self.is_err() {
+            *counter += 1;
+        }
+
+        self
+    }
+
+    fn ok_or_err_value<U, F>(self, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+        U: From<E>,
+    {
+        match self {
+            Ok(x) => f(x),
+            Err(e) => U::from(e),
+        }
+    }
+
+    fn require(self, context: &str) -> T
+    where
+        E: Display,
+    {
+        match self {
+            Ok(x) => x,
+            Err(e) => panic!("{context}: {e}"),
+        }
+    }
+
+    fn and_then_ref<U, F>(&self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(&T) -> Result<U, E>,
+        E: Clone,
+    {
+        match self {
+            Ok(x) => f(x),
+            Err(e) => Err(e.clone()),
+        }
+    }
+
+    fn and_then_ref_borrowed<'a, U, F>(&'a self, f: F) -> Result<U, &'a E>
+    where
+        T: 'a,
+        E: 'a,
+        F: FnOnce(&'a T) -> Result<U, &'a E>,
+    {
+        self.as_ref().and_then(f)
+    }
+
+    fn discard_ok(self) -> Result<(), E> {
+        self.map(drop)
+    }
+
+    fn discard_err(self) -> Result<T, ()> {
+        self.map_err(drop)
+    }
+
+    #[cfg(feature = "std")]
+    fn expect_err_with<F>(self, f: F) -> E
+    where
+        F: FnOnce(&T) -> String,
+    {
+        match self {
+            Ok(x) => panic!("{}", f(&x)),
+            Err(e) => e,
+        }
+    }
+
+    fn tap<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Result<&T, &E>),
+    {
+        f(self.as_ref());
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn chain_context<C>(self, ctx: C) -> Result<T, ContextChain>
+    where
+        C: Display,
+        E: IntoContextChain,
+    {
+        self.map_err(|e| {
+            let mut chain = e.into_context_chain();
+            chain.push_front(ctx.to_string());
+            chain
+        })
+    }
+
+    fn map_into<U>(self) -> Result<U, E>
+    where
+        U: From<T>,
+    {
+        self.map(U::from)
+    }
+
+    fn map_err_into<E2>(self) -> Result<T, E2>
+    where
+        E2: From<E>,
+    {
+        self.map_err(E2::from)
+    }
+
+    fn map_err_default<E2>(self) -> Result<T, E2>
+    where
+        E2: Default,
+    {
+        self.map_err(|_| E2::default())
+    }
+
+    fn ok_and<P>(self, predicate: P) -> bool
+    where
+        P: FnOnce(T) -> bool,
+    {
+        match self {
+            Ok(x) => predicate(x),
+            Err(_) => false,
+        }
+    }
+
+    fn update_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Ok(x) = self {
+            f(x);
+        }
+    }
+
+    fn ok_iter(self) -> IntoIter<T> {
+        self.ok().into_iter()
+    }
+
+    fn err_iter(self) -> IntoIter<E> {
+        self.err().into_iter()
+    }
+
+    fn map_both<U, F2, F, G>(self, ok: F, err: G) -> Result<U, F2>
+    where
+        F: FnOnce(T) -> U,
+        G: FnOnce(E) -> F2,
+    {
+        match self {
+            Ok(x) => Ok(ok(x)),
+            Err(e) => Err(err(e)),
+        }
+    }
+
+    fn into_inner<U>(self) -> U
+    where
+        T: Into<U>,
+        E: Into<U>,
+    {
+        match self {
+            Ok(x) => x.into(),
+            Err(e) => e.into(),
+        }
+    }
+
+    fn assert_ok(&self) -> &T
+    where
+        E: Debug,
+    {
+        match self {
+            Ok(x) => x,
+            Err(e) => panic!("called `ResultExt::assert_ok` on an `Err` value: {:?}", e),
+        }
+    }
+
+    fn assert_err(&self) -> &E
+    where
+        T: Debug,
+    {
+        match self {
+            Ok(x) => panic!("called `ResultExt::assert_err` on an `Ok` value: {:?}", x),
+            Err(e) => e,
+        }
+    }
+
+    fn or_first_ok<I, F>(self, fallbacks: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce() -> Self,
+    {
+        let mut res = self;
+        let mut fallbacks = fallbacks.into_iter();
+
+        while res.is_err() {
+            match fallbacks.next() {
+                Some(f) => res = f(),
+                None => break,
+            }
+        }
+
+        res
+    }
+
+    fn pipe<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(Result<T, E>) -> R,
+    {
+        f(self)
+    }
+
+    fn ok_slice(&self) -> &[T] {
+        match self {
+            Ok(x) => slice::from_ref(x),
+            Err(_) => &[],
+        }
+    }
+
+    fn err_slice(&self) -> &[E] {
+        match self {
+            Ok(_) => &[],
+            Err(e) => slice::from_ref(e),
+        }
+    }
+
+    fn map_or_else_ref<U, D, F>(&self, default: D, f: F) -> U
+    where
+        D: FnOnce(&E) -> U,
+        F: FnOnce(&T) -> U,
+    {
+        match self {
+            Ok(x) => f(x),
+            Err(e) => default(e),
+        }
+    }
+
+    fn and_then_both<U, F2, F, G>(self, ok: F, err: G) -> Result<U, F2>
+    where
+        F: FnOnce(T) -> Result<U, F2>,
+        G: FnOnce(E) -> Result<U, F2>,
+    {
+        match self {
+            Ok(x) => ok(x),
+            Err(e) => err(e),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn map_err_context<C, F>(self, f: F) -> Result<T, String>
+    where
+        E: Display,
+        F: FnOnce(&E) -> C,
+        C: Display,
+    {
+        self.map_err(|e| format!("{}: {e}", f(&e)))
+    }
+
+    fn count_into(self, ok: &mut usize, err: &mut usize) -> Self {
+        match self {
+            Ok(_) => *ok += 1,
+            Err(_) => *err += 1,
+        }
+
+        self
+    }
+
+    fn ok_filter_or<E2, P>(self, predicate: P, err: E2) -> Result<T, E2>
+    where
+        P: FnOnce(&T) -> bool,
+        E: Into<E2>,
+    {
+        match self {
+            Ok(x) if predicate(&x) => Ok(x),
+            Ok(_) => Err(err),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn display_either(self) -> String
+    where
+        T: Display,
+        E: Display,
+    {
+        match self {
+            Ok(x) => x.to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn ok_or_write<W>(self, w: &mut W) -> Option<T>
+    where
+        W: Write,
+        E: Display,
+    {
+        match self {
+            Ok(x) => Some(x),
+            Err(e) => {
+                let _ = writeln!(w, "{e}");
+                None
+            }
+        }
+    }
+}
+
+/// Extra methods for [`Result`]s wrapping an [`Option`].
+pub trait ResultOptionExt<T, E> {
+    /// Transposes `self` into an [`Option`] of a [`Result`].
+    ///
+    /// Same as [`Result::transpose`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultOptionExt;
+    ///
+    /// assert_eq!(Ok::<_, ()>(Some(1)).flatten_ok(), Some(Ok(1)));
+    /// assert_eq!(Ok::<Option<i32>, ()>(None).flatten_ok(), None);
+    /// assert_eq!(Err::<Option<i32>, _>("abc").flatten_ok(), Some(Err("abc")));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn flatten_ok(self) -> Option<Result<T, E>>;
+
+    /// Returns the wrapped value if `self` is `Ok(Some(_))`,
+    /// or [`None`] otherwise.
+    ///
+    /// The error, if any, is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultOptionExt;
+    ///
+    /// assert_eq!(Ok::<_, ()>(Some(1)).ok_flatten(), Some(1));
+    /// assert_eq!(Ok::<Option<i32>, ()>(None).ok_flatten(), None);
+    /// assert_eq!(Err::<Option<i32>, _>("abc").ok_flatten(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn ok_flatten(self) -> Option<T>;
+}
+
+impl<T, E> ResultOptionExt<T, E> for Result<Option<T>, E> {
+    fn flatten_ok(self) -> Option<Result<T, E>> {
+        self.transpose()
+    }
+
+    fn ok_flatten(self) -> Option<T> {
+        self.ok().flatten()
+    }
+}
+
+/// Extra methods for [`Result`]s wrapping another [`Result`].
+pub trait ResultResultExt<T, E1, E2> {
+    /// Flattens `self` into a single [`Result`],
+    /// converting either error into `E` with [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use option_extra::ResultResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError {
+    ///     Io(&'static str),
+    ///     Parse(&'static str),
+    /// }
+    ///
+    /// struct IoError(&'static str);
+    /// struct ParseError(&'static str);
+    ///
+    /// impl From<IoError> for AppError {
+    ///     fn from(e: IoError) -> Self {
+    ///         AppError::Io(e.0)
+    ///     }
+    /// }
+    ///
+    /// impl From<ParseError> for AppError {
+    ///     fn from(e: ParseError) -> Self {
+    ///         AppError::Parse(e.0)
+    ///     }
+    /// }
+    ///
+    /// let ok: Result<Result<i32, ParseError>, IoError> = Ok(Ok(1));
+    /// let inner: Result<Result<i32, ParseError>, IoError> = Ok(Err(ParseError("bad digit")));
+    /// let outer: Result<Result<i32, ParseError>, IoError> = Err(IoError("no such file"));
+    ///
+    /// assert_eq!(ok.flatten_err_into::<AppError>(), Ok(1));
+    /// assert_eq!(inner.flatten_err_into::<AppError>(), Err(AppError::Parse("bad digit")));
+    /// assert_eq!(outer.flatten_err_into::<AppError>(), Err(AppError::Io("no such file")));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn flatten_err_into<E>(self) -> Result<T, E>
+    where
+        E: From<E1> + From<E2>;
+}
+
+impl<T, E1, E2> ResultResultExt<T, E1, E2> for Result<Result<T, E1>, E2> {
+    fn flatten_err_into<E>(self) -> Result<T, E>
+    where
+        E: From<E1> + From<E2>,
+    {
+        match self {
+            Ok(Ok(x)) => Ok(x),
+            Ok(Err(e1)) => Err(E::from(e1)),
+            Err(e2) => Err(E::from(e2)),
+        }
+    }
 }